@@ -0,0 +1,89 @@
+//! Generates one `#[test]` per (year, day, part, sample file) that has a
+//! recorded answer in `inputs/YYYY/dayN/answers.toml`.
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn expected_literal(value: &toml::Value) -> Option<String> {
+    match value {
+        toml::Value::Integer(n) => Some(n.to_string()),
+        toml::Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+/// Year that `inputs/dayN` directories from before the per-year layout belong to
+const LEGACY_YEAR: u16 = 2023;
+
+/// Every `dayN` directory directly under `dir` that has an answers file
+fn answer_files(dir: &Path, year: u16, days: &mut Vec<(u16, u8, PathBuf, toml::Table)>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        let Some(Ok(day)) = name.strip_prefix("day").map(str::parse::<u8>) else {
+            continue;
+        };
+        let path = entry.path().join("answers.toml");
+        let Ok(contents) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let answers: toml::Table = contents
+            .parse()
+            .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        days.push((year, day, entry.path(), answers));
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=inputs");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let inputs = Path::new(&manifest_dir).join("inputs");
+    let mut days = vec![];
+    answer_files(&inputs, LEGACY_YEAR, &mut days);
+    // A package without the puzzle inputs just gets no sample tests
+    for entry in fs::read_dir(&inputs).into_iter().flatten().flatten() {
+        if let Ok(year) = entry.file_name().to_string_lossy().parse::<u16>() {
+            answer_files(&entry.path(), year, &mut days);
+        }
+    }
+    days.sort_by_key(|(year, day, _, _)| (*year, *day));
+
+    let mut out = String::new();
+    for (year, day, dir, answers) in days {
+        for (stem, parts) in answers.iter() {
+            if !stem.starts_with("sample") {
+                continue;
+            }
+            let path = dir.join(format!("{}.txt", stem));
+            if !path.exists() {
+                continue;
+            }
+            for (part, key) in [("One", "part1"), ("Two", "part2")] {
+                let Some(expected) = parts.get(key).and_then(expected_literal) else {
+                    continue;
+                };
+                writeln!(
+                    out,
+                    "#[test]\nfn y{}_day{:02}_{}_{}() {{\n    check({}, {}, include_str!({:?}), Part::{}, {:?});\n}}\n",
+                    year,
+                    day,
+                    stem,
+                    key,
+                    year,
+                    day,
+                    path.display().to_string(),
+                    part,
+                    expected
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("sample_tests.rs");
+    fs::write(dest, out).unwrap();
+}
@@ -0,0 +1,205 @@
+use advent23::error::{Error, Result};
+use advent23::solution::Part;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::output::{part_number, print_json, Format};
+use crate::runner::{format_duration, parse_toml, read_input, InputArgs};
+
+#[derive(clap::Args, Debug)]
+pub struct BenchArgs {
+    /// Day to benchmark
+    day: u8,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[clap(long, short, action)]
+    part2: bool,
+
+    /// Number of measured runs
+    #[clap(long, short, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// Number of unmeasured runs before measuring
+    #[clap(long, short, default_value_t = 10)]
+    warmup: u64,
+
+    /// Save the results as a named baseline
+    #[clap(long)]
+    save_baseline: Option<String>,
+
+    /// Compare the results against a previously saved baseline
+    #[clap(long)]
+    baseline: Option<String>,
+}
+
+/// Summary of a set of timings, in nanoseconds
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+        let n = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / n;
+        let variance = nanos.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[nanos.len() / 2 - 1] + nanos[nanos.len() / 2]) / 2.0
+        } else {
+            nanos[nanos.len() / 2]
+        };
+        // nearest-rank percentile
+        let p95 = nanos[((0.95 * n).ceil() as usize).max(1) - 1];
+        Self {
+            min: nanos[0],
+            median,
+            mean,
+            p95,
+            stddev: variance.sqrt(),
+        }
+    }
+
+    fn fields(&self) -> [f64; 5] {
+        [self.min, self.median, self.mean, self.p95, self.stddev]
+    }
+}
+
+/// Timings for one day and part, split by phase
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Report {
+    pub iterations: u64,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// A benchmark run as emitted by `--format json`
+#[derive(Debug, Serialize)]
+struct BenchRecord<'a> {
+    day: u8,
+    part: u8,
+    input: &'a str,
+    warmup: u64,
+    #[serde(flatten)]
+    report: &'a Report,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline: Option<&'a Report>,
+}
+
+fn baseline_path(name: &str, year: u16, day: u8, part: Part) -> PathBuf {
+    config::settings().in_project(format!(
+        "target/advent23-bench/{}/{}/day{}-part{}.toml",
+        name, year, day, part
+    ))
+}
+
+fn nanos(n: f64) -> String {
+    format_duration(Duration::from_nanos(n as u64))
+}
+
+fn print_stats(label: &str, stats: &Stats, baseline: Option<&Stats>) {
+    println!(
+        "{:<8} {:>10} {:>10} {:>10} {:>10} {:>10}",
+        label,
+        nanos(stats.min),
+        nanos(stats.median),
+        nanos(stats.mean),
+        nanos(stats.p95),
+        nanos(stats.stddev)
+    );
+    if let Some(baseline) = baseline {
+        let deltas: Vec<String> = stats
+            .fields()
+            .iter()
+            .zip(baseline.fields())
+            .map(|(now, then)| {
+                if then == 0.0 {
+                    "n/a".to_string()
+                } else {
+                    format!("{:+.1}%", (now - then) / then * 100.0)
+                }
+            })
+            .collect();
+        println!(
+            "{:<8} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "vs base", deltas[0], deltas[1], deltas[2], deltas[3], deltas[4]
+        );
+    }
+}
+
+pub fn run_bench(args: &BenchArgs, year: u16, format: Format) -> Result<()> {
+    let solution = advent23::day_in(year, args.day)?;
+    let part = if args.part2 { Part::Two } else { Part::One };
+    let (path, input) = args.input.load(year, args.day)?;
+
+    for _ in 0..args.warmup {
+        solution.solve(&input, part).map_err(|e| e.in_file(&path))?;
+    }
+    let mut parse_times = vec![];
+    let mut solve_times = vec![];
+    for _ in 0..args.iterations {
+        let start = Instant::now();
+        let parsed = solution.parse(&input).map_err(|e| e.in_file(&path))?;
+        parse_times.push(start.elapsed());
+        let start = Instant::now();
+        solution.solve_parsed(&parsed, part)?;
+        solve_times.push(start.elapsed());
+    }
+    let report = Report {
+        iterations: args.iterations,
+        parse: Stats::from_samples(&parse_times),
+        solve: Stats::from_samples(&solve_times),
+    };
+
+    let baseline: Option<Report> = match &args.baseline {
+        Some(name) => {
+            let path = baseline_path(name, year, args.day, part)
+                .display()
+                .to_string();
+            Some(parse_toml(&path, &read_input(&path)?)?)
+        }
+        None => None,
+    };
+
+    match format {
+        Format::Plain => {
+            println!(
+                "day {} part {} ({} iterations, {} warmup)",
+                args.day, part, args.iterations, args.warmup
+            );
+            println!(
+                "{:<8} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                "", "min", "median", "mean", "p95", "stddev"
+            );
+            print_stats("parse", &report.parse, baseline.as_ref().map(|b| &b.parse));
+            print_stats("solve", &report.solve, baseline.as_ref().map(|b| &b.solve));
+        }
+        Format::Json => print_json(&BenchRecord {
+            day: args.day,
+            part: part_number(part),
+            input: &path,
+            warmup: args.warmup,
+            report: &report,
+            baseline: baseline.as_ref(),
+        }),
+    }
+
+    if let Some(name) = &args.save_baseline {
+        let path = baseline_path(name, year, args.day, part);
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, toml::to_string(&report).unwrap()))
+            .map_err(|e| Error::io(&path.display().to_string(), e))?;
+        eprintln!("saved baseline to {}", path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use advent23::error::{Error, ParseError, Result};
+use advent23::years::DEFAULT_YEAR;
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::output::{Color, Format};
+use crate::runner::parse_toml;
+
+/// Name of the project-local config file, looked for in the current directory and its parents
+pub const PROJECT_FILE: &str = "advent23.toml";
+
+/// Contents of a config file; anything left out falls back to the defaults
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Directory holding `YYYY/dayN/` input directories
+    pub inputs: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub format: Option<Format>,
+    pub color: Option<Color>,
+    /// File holding the adventofcode.com session token
+    pub session_file: Option<PathBuf>,
+}
+
+/// Settings given on the command line, which override the config file
+#[derive(clap::Args, Debug, Default)]
+pub struct Overrides {
+    /// Directory holding the puzzle inputs [default: ./inputs]
+    #[arg(long, global = true)]
+    pub inputs: Option<PathBuf>,
+
+    /// Puzzle year [default: 2023]
+    #[arg(long, global = true)]
+    pub year: Option<u16>,
+
+    /// Output format; `json` prints one object per line and sends everything else to stderr [default: plain]
+    #[arg(long, global = true, value_enum)]
+    pub format: Option<Format>,
+
+    /// Whether to color output [default: auto]
+    #[arg(long, global = true, value_enum)]
+    pub color: Option<Color>,
+
+    /// File holding the adventofcode.com session token [default: ~/.config/advent23/session]
+    #[arg(long, global = true)]
+    pub session_file: Option<PathBuf>,
+}
+
+/// A config file that was found, and which kind it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigFile {
+    /// An `advent23.toml` at or above the current directory, marking the project root
+    Project(PathBuf),
+    /// The user's `advent23/config.toml`
+    User(PathBuf),
+}
+
+impl ConfigFile {
+    pub fn path(&self) -> &Path {
+        match self {
+            ConfigFile::Project(path) | ConfigFile::User(path) => path,
+        }
+    }
+
+    /// The project root, for a project config
+    fn project_dir(&self) -> Option<&Path> {
+        match self {
+            ConfigFile::Project(path) => path.parent(),
+            ConfigFile::User(_) => None,
+        }
+    }
+}
+
+/// Where each setting ended up, after the config file, flags and environment have had their say
+#[derive(Debug)]
+pub struct Settings {
+    /// Directory holding the project's `advent23.toml`, if one was found
+    pub project: Option<PathBuf>,
+    pub inputs: PathBuf,
+    pub year: u16,
+    pub format: Format,
+    pub color: bool,
+    pub session_file: Option<PathBuf>,
+}
+
+impl Settings {
+    /// `path` within the project: next to its `advent23.toml`, else in the current directory
+    pub fn in_project(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.project.as_deref().unwrap_or(Path::new(".")).join(path)
+    }
+}
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+/// The settings in effect; the defaults until [`init`] has run
+pub fn settings() -> &'static Settings {
+    SETTINGS
+        .get_or_init(|| resolve(Config::default(), None, &Overrides::default(), |_| None).unwrap())
+}
+
+/// Load the config file, apply `overrides` and the environment on top, and make the result
+/// what [`settings`] returns
+pub fn init(overrides: &Overrides) -> Result<&'static Settings> {
+    let file = find();
+    let config = match &file {
+        Some(file) => {
+            let name = file.path().display().to_string();
+            let contents = fs::read_to_string(file.path()).map_err(|e| Error::io(&name, e))?;
+            parse_toml(&name, &contents)?
+        }
+        None => Config::default(),
+    };
+    let settings = resolve(config, file.as_ref(), overrides, |name| env::var(name).ok())?;
+    Ok(SETTINGS.get_or_init(|| settings))
+}
+
+/// `$XDG_CONFIG_HOME`, or `~/.config`
+pub fn user_config_dir() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => Some(PathBuf::from(env::var_os("HOME")?).join(".config")),
+    }
+}
+
+/// The nearest `advent23.toml` at or above the current directory, else the user's
+/// `advent23/config.toml`
+fn find() -> Option<ConfigFile> {
+    let cwd = env::current_dir().ok()?;
+    let project = cwd
+        .ancestors()
+        .map(|dir| dir.join(PROJECT_FILE))
+        .find(|path| path.is_file())
+        .map(ConfigFile::Project);
+    let user = || {
+        let path = user_config_dir()?.join("advent23").join("config.toml");
+        path.is_file().then_some(ConfigFile::User(path))
+    };
+    project.or_else(user)
+}
+
+fn from_env<T: clap::ValueEnum>(name: &str, value: String) -> Result<T> {
+    T::from_str(&value, true)
+        .map_err(|_| ParseError::new(format!("invalid value for {}", name), &value).into())
+}
+
+/// Combine every source of settings: the environment beats flags, which beat the config file.
+///
+/// Relative paths in the config file are relative to the directory it was found in. Only a
+/// project config moves the default inputs directory, to `inputs/` next to it.
+fn resolve(
+    config: Config,
+    file: Option<&ConfigFile>,
+    overrides: &Overrides,
+    var: impl Fn(&str) -> Option<String>,
+) -> Result<Settings> {
+    let config_dir = file.and_then(|file| file.path().parent());
+    let relative_to_config = |path: PathBuf| match config_dir {
+        Some(dir) => dir.join(path),
+        None => path,
+    };
+    let project = file
+        .and_then(ConfigFile::project_dir)
+        .map(Path::to_path_buf);
+
+    let inputs = var("ADVENT23_INPUTS")
+        .map(PathBuf::from)
+        .or_else(|| overrides.inputs.clone())
+        .or_else(|| config.inputs.map(relative_to_config))
+        .unwrap_or_else(|| project.as_deref().unwrap_or(Path::new(".")).join("inputs"));
+
+    let year = match var("ADVENT23_YEAR") {
+        Some(year) => year
+            .parse()
+            .map_err(|_| ParseError::new("invalid value for ADVENT23_YEAR", &year))?,
+        None => overrides.year.or(config.year).unwrap_or(DEFAULT_YEAR),
+    };
+
+    let format = match var("ADVENT23_FORMAT") {
+        Some(format) => from_env("ADVENT23_FORMAT", format)?,
+        None => overrides.format.or(config.format).unwrap_or_default(),
+    };
+
+    let color = match var("ADVENT23_COLOR") {
+        Some(color) => from_env("ADVENT23_COLOR", color)?,
+        None if var("NO_COLOR").is_some_and(|v| !v.is_empty()) => Color::Never,
+        None => overrides.color.or(config.color).unwrap_or_default(),
+    };
+    let color = match color {
+        Color::Auto => io::stdout().is_terminal(),
+        Color::Always => true,
+        Color::Never => false,
+    };
+
+    let session_file = var("ADVENT23_SESSION_FILE")
+        .map(PathBuf::from)
+        .or_else(|| overrides.session_file.clone())
+        .or_else(|| config.session_file.map(relative_to_config))
+        .or_else(|| Some(user_config_dir()?.join("advent23").join("session")));
+
+    Ok(Settings {
+        project,
+        inputs,
+        year,
+        format,
+        color,
+        session_file,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(toml: &str) -> Config {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[test]
+    fn flags_override_config_and_environment_overrides_both() {
+        let file = "year = 2022\nformat = \"json\"\ninputs = \"puzzles\"";
+        let project = ConfigFile::Project(PathBuf::from("/project/advent23.toml"));
+
+        let settings = resolve(config(file), Some(&project), &Overrides::default(), |_| {
+            None
+        })
+        .unwrap();
+        assert_eq!(settings.year, 2022);
+        assert_eq!(settings.format, Format::Json);
+        assert_eq!(settings.inputs, PathBuf::from("/project/puzzles"));
+
+        let flags = Overrides {
+            year: Some(2021),
+            format: Some(Format::Plain),
+            ..Overrides::default()
+        };
+        let settings = resolve(config(file), Some(&project), &flags, |_| None).unwrap();
+        assert_eq!(settings.year, 2021);
+        assert_eq!(settings.format, Format::Plain);
+
+        let env = |name: &str| (name == "ADVENT23_YEAR").then(|| String::from("2020"));
+        let settings = resolve(config(file), Some(&project), &flags, env).unwrap();
+        assert_eq!(settings.year, 2020);
+    }
+
+    #[test]
+    fn inputs_default_to_next_to_a_project_config() {
+        let resolve = |file: Option<ConfigFile>, toml: &str| {
+            resolve(config(toml), file.as_ref(), &Overrides::default(), |_| None).unwrap()
+        };
+        let project = || Some(ConfigFile::Project(PathBuf::from("/project/advent23.toml")));
+        let settings = resolve(project(), "");
+        assert_eq!(settings.inputs, PathBuf::from("/project/inputs"));
+        assert_eq!(
+            settings.in_project("src/years"),
+            PathBuf::from("/project/src/years")
+        );
+
+        let user = || Some(ConfigFile::User(PathBuf::from("/xdg/advent23/config.toml")));
+        let settings = resolve(user(), "color = \"never\"");
+        assert_eq!(settings.inputs, PathBuf::from("./inputs"));
+        assert_eq!(settings.project, None);
+        let settings = resolve(user(), "inputs = \"puzzles\"");
+        assert_eq!(settings.inputs, PathBuf::from("/xdg/advent23/puzzles"));
+
+        let settings = resolve(None, "");
+        assert_eq!(settings.inputs, PathBuf::from("./inputs"));
+        assert_eq!(settings.in_project("target"), PathBuf::from("./target"));
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        assert!(toml::from_str::<Config>("colour = true").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use std::{fmt, io};
+
+/// Malformed puzzle input.
+///
+/// Parsers usually only know the offending text and where it sits in its line;
+/// callers fill in the line number and file name as the error travels outwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// 1-based line number, 0 if unknown
+    pub line: usize,
+    /// 1-based column, 0 if unknown
+    pub column: usize,
+    /// Byte offset of `text` within its line, if known
+    pub offset: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        Self {
+            file: None,
+            line: 0,
+            column: 0,
+            offset: None,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error about `token`, which must be a slice of `line`
+    pub fn in_line(message: impl Into<String>, line: &str, token: &str) -> Self {
+        let mut e = Self::new(message, token).at_column(column_of(line, token));
+        e.offset = offset_of(line, token);
+        e
+    }
+
+    /// Move a position found within `part`, a slice of `line`, to the same spot in `line`
+    pub fn within(mut self, line: &str, part: &str) -> Self {
+        if let (Some(start), Some(offset)) = (offset_of(line, part), self.offset) {
+            self.offset = Some(start + offset);
+            self.column = line[..start + offset].chars().count() + 1;
+        }
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = column;
+        self
+    }
+
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.as_deref().unwrap_or("<input>"))?;
+        if self.line > 0 {
+            write!(f, ":{}", self.line)?;
+            if self.column > 0 {
+                write!(f, ":{}", self.column)?;
+            }
+        }
+        write!(f, ": {}: {:?}", self.message, self.text)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Byte offset at which `token` starts, given that it is a slice of `line`
+pub fn offset_of(line: &str, token: &str) -> Option<usize> {
+    let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+    line.get(..offset)?;
+    (offset + token.len() <= line.len()).then_some(offset)
+}
+
+/// 1-based column at which `token` starts, given that it is a slice of `line`
+pub fn column_of(line: &str, token: &str) -> usize {
+    match offset_of(line, token) {
+        Some(offset) => line[..offset].chars().count() + 1,
+        None => 0,
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: String,
+        source: io::Error,
+    },
+    Parse(ParseError),
+    /// The input parsed, but has no answer under the puzzle's rules
+    Unsolvable(String),
+    Unimplemented(u8),
+    /// Talking to the puzzle server failed
+    Fetch(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn io(path: &str, source: io::Error) -> Self {
+        Error::Io {
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn unsolvable(reason: impl Into<String>) -> Self {
+        Error::Unsolvable(reason.into())
+    }
+
+    /// Attach a file name to a parse error that doesn't have one yet
+    pub fn in_file(self, file: &str) -> Self {
+        match self {
+            Error::Parse(e) if e.file.is_none() => Error::Parse(e.in_file(file)),
+            e => e,
+        }
+    }
+
+    /// Process exit code for this category of error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Io { .. } => 3,
+            Error::Parse(_) => 4,
+            Error::Unsolvable(_) => 5,
+            Error::Unimplemented(_) => 6,
+            Error::Fetch(_) => 7,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path, source),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Unsolvable(reason) => write!(f, "no solution: {}", reason),
+            Error::Unimplemented(day) => write!(f, "day {} has not yet been implemented", day),
+            Error::Fetch(reason) => write!(f, "fetch failed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_counts_characters_from_one() {
+        let line = "Game 2: 3 blu, 4 red";
+        assert_eq!(column_of(line, &line[10..13]), 11);
+        assert_eq!(column_of(line, line), 1);
+        assert_eq!(column_of(line, &String::from("blu")), 0);
+    }
+
+    #[test]
+    fn within_moves_the_position_into_the_whole_line() {
+        let line = "Card 1: 41 x8 | 83";
+        let e = ParseError::in_line("invalid number", &line[8..], &line[11..13])
+            .within(line, &line[8..]);
+        assert_eq!((e.offset, e.column), (Some(11), 12));
+    }
+
+    #[test]
+    fn display_includes_position_when_known() {
+        let e = ParseError::new("unknown color", "blu");
+        assert_eq!(e.to_string(), "<input>: unknown color: \"blu\"");
+        let e = e.at_column(11).on_line(2).in_file("input.txt");
+        assert_eq!(e.to_string(), "input.txt:2:11: unknown color: \"blu\"");
+    }
+}
@@ -0,0 +1,224 @@
+use advent23::error::{Error, Result};
+use advent23::solution::Part;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::config;
+use crate::runner::input_path;
+
+/// Never hit the server more often than this, even across invocations
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// When the server was last hit, shared by every invocation from any directory: in the
+/// user's config directory, or failing that the project's `target/`
+fn last_request() -> PathBuf {
+    match config::user_config_dir() {
+        Some(dir) => dir.join("advent23").join("last-request"),
+        None => config::settings().in_project("target/advent23-fetch/last-request"),
+    }
+}
+
+/// Which puzzle server to talk to, shared by `fetch` and `submit`
+#[derive(clap::Args, Debug)]
+pub struct ServerArgs {
+    /// Server to talk to
+    #[clap(
+        long,
+        env = "ADVENT23_BASE_URL",
+        default_value = "https://adventofcode.com"
+    )]
+    pub base_url: String,
+}
+
+impl ServerArgs {
+    pub fn client(&self) -> Result<Client> {
+        Ok(Client::new(&self.base_url, &session()?))
+    }
+}
+
+#[derive(clap::Args, Debug)]
+pub struct FetchArgs {
+    /// Day to download
+    #[clap(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+/// An HTTP client authenticated with an Advent of Code session cookie
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set(
+                "User-Agent",
+                concat!("advent23/", env!("CARGO_PKG_VERSION")),
+            )
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        read_response(&url, self.request("GET", &url).call())
+    }
+
+    /// Post an answer and return the page the server replies with
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+        let response = self
+            .request("POST", &url)
+            .send_form(&[("level", level.as_str()), ("answer", answer)]);
+        read_response(&url, response)
+    }
+}
+
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    let response = response.map_err(|e| match e {
+        ureq::Error::Status(code, response) => {
+            let body = response.into_string().unwrap_or_default();
+            let reason = body.lines().next().unwrap_or_default().to_string();
+            Error::Fetch(format!("{}: HTTP {} {}", url, code, reason))
+        }
+        e => Error::Fetch(format!("{}: {}", url, e)),
+    })?;
+    response
+        .into_string()
+        .map_err(|e| Error::Fetch(format!("{}: {}", url, e)))
+}
+
+fn session() -> Result<String> {
+    if let Ok(token) = env::var("ADVENT23_SESSION") {
+        return Ok(token.trim().to_string());
+    }
+    let path = config::settings()
+        .session_file
+        .as_ref()
+        .ok_or_else(|| Error::Fetch(String::from("no session token: set ADVENT23_SESSION")))?;
+    match fs::read_to_string(path) {
+        Ok(token) => Ok(token.trim().to_string()),
+        Err(_) => Err(Error::Fetch(format!(
+            "no session token: set ADVENT23_SESSION or write it to {}",
+            path.display()
+        ))),
+    }
+}
+
+/// Sleep until `MIN_INTERVAL` has passed since the last recorded request, then record a new one
+pub fn wait_for_turn() -> Result<()> {
+    let stamp = last_request();
+    let last = fs::metadata(&stamp).and_then(|m| m.modified()).ok();
+    if let Some(elapsed) = last.and_then(|t| SystemTime::now().duration_since(t).ok()) {
+        if elapsed < MIN_INTERVAL {
+            let wait = MIN_INTERVAL - elapsed;
+            eprintln!("waiting {:.1}s before the next request", wait.as_secs_f64());
+            thread::sleep(wait);
+        }
+    }
+    let stamp_name = stamp.display().to_string();
+    fs::create_dir_all(stamp.parent().unwrap())
+        .and_then(|_| fs::write(&stamp, ""))
+        .map_err(|e| Error::io(&stamp_name, e))
+}
+
+pub fn run_fetch(args: &FetchArgs, year: u16) -> Result<()> {
+    let path = input_path(year, args.day);
+    if Path::new(&path).exists() {
+        eprintln!("{} is already downloaded", path);
+        return Ok(());
+    }
+    let client = args.server.client()?;
+    wait_for_turn()?;
+    let input = client.input(year, args.day)?;
+    fs::create_dir_all(Path::new(&path).parent().unwrap())
+        .and_then(|_| fs::write(&path, input))
+        .map_err(|e| Error::io(&path, e))?;
+    eprintln!("saved {}", path);
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    /// Serve a single request, replying with `status` and `body`, and return the request
+    pub fn stub_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let length = head
+                .lines()
+                .find_map(|l| {
+                    let l = l.to_lowercase();
+                    l.strip_prefix("content-length:")?.trim().parse().ok()
+                })
+                .unwrap_or(0);
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+            head.push_str(&String::from_utf8(request_body).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            head
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn downloads_input_with_session_cookie() {
+        let (url, server) = stub_server("200 OK", "1abc2\n");
+        let input = Client::new(&url, "secret").input(2023, 4).unwrap();
+        assert_eq!(input, "1abc2\n");
+        let head = server.join().unwrap();
+        assert!(head.starts_with("GET /2023/day/4/input HTTP/1.1"));
+        assert!(head.contains("session=secret"));
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let (url, server) = stub_server(
+            "404 Not Found",
+            "Please don't repeatedly request this endpoint before it unlocks!\n",
+        );
+        let e = Client::new(&url, "secret").input(2023, 25).unwrap_err();
+        server.join().unwrap();
+        assert_eq!(e.exit_code(), 7);
+        assert!(e.to_string().contains("HTTP 404 Please don't"));
+    }
+}
@@ -0,0 +1,433 @@
+//! Searches over graphs given as a neighbor function.
+//!
+//! Nodes are any hashable value, and edges come from a closure, so the same
+//! searches work on grid points, named nodes or whole puzzle states:
+//!
+//! ```
+//! use advent23::graph::{bfs, dijkstra};
+//!
+//! // 1 -> 2 -> 4 costs 1 + 5, 1 -> 3 -> 4 costs 2 + 1
+//! let edges = |n: &u8| match n {
+//!     1 => vec![(2, 1), (3, 2)],
+//!     2 => vec![(4, 5)],
+//!     3 => vec![(4, 1)],
+//!     _ => vec![],
+//! };
+//! assert_eq!(dijkstra(1, edges, |&n| n == 4), Some((vec![1, 3, 4], 3)));
+//!
+//! let hops = bfs(1, |n| edges(n).into_iter().map(|(m, _)| m));
+//! assert_eq!(hops.cost(&4), Some(2));
+//! assert_eq!(hops.path_to(&4), Some(vec![1, 2, 4]));
+//! ```
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The best known way to reach every node a search found
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    /// Nodes in the order they were found
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    /// Cost of each node and the index of the node it was reached from
+    best: Vec<(C, Option<usize>)>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            best: vec![(zero, None)],
+        }
+    }
+
+    /// Record reaching `node` from `parent` at `cost`; returns its index, and whether
+    /// that was an improvement
+    fn offer(
+        &mut self,
+        node: N,
+        cost: C,
+        parent: usize,
+        better: impl Fn(C, C) -> bool,
+    ) -> (usize, bool) {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => {
+                let i = *entry.get();
+                let improved = better(cost, self.best[i].0);
+                if improved {
+                    self.best[i] = (cost, Some(parent));
+                }
+                (i, improved)
+            }
+            Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(i);
+                self.best.push((cost, Some(parent)));
+                (i, true)
+            }
+        }
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// Cost of the best path found to `node`
+    pub fn cost(&self, node: &N) -> Option<C> {
+        Some(self.best[*self.index.get(node)?].0)
+    }
+
+    /// The best path found from the start to `node`, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut i = Some(*self.index.get(node)?);
+        let mut path = vec![];
+        while let Some(at) = i {
+            path.push(self.nodes[at].clone());
+            i = self.best[at].1;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node found, with its cost, in the order they were found
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes
+            .iter()
+            .zip(self.best.iter().map(|&(cost, _)| cost))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Always false: a search finds at least its start
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+/// Breadth-first search: every node reachable from `start`, with the fewest edges
+/// needed to reach it. [`Paths::iter`] yields them nearest first.
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let (node, depth) = (paths.nodes[i].clone(), paths.best[i].0);
+        for next in neighbors(&node) {
+            if !paths.contains(&next) {
+                let (j, _) = paths.offer(next, depth + 1, i, |_, _| false);
+                queue.push_back(j);
+            }
+        }
+    }
+    paths
+}
+
+/// Depth-first search: every node reachable from `start`, in preorder
+pub fn dfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut order = vec![];
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let mut next: Vec<N> = neighbors(&node)
+            .into_iter()
+            .filter(|n| !seen.contains(n))
+            .collect();
+        // so the first neighbor is visited first
+        next.reverse();
+        stack.extend(next);
+        order.push(node);
+    }
+    order
+}
+
+/// Best-first search shared by Dijkstra and A*; stops once a `goal` node is settled
+fn search<N, C, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, C>, Option<usize>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start, C::default());
+    let mut settled = HashSet::new();
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&paths.nodes[0]), 0))]);
+    while let Some(Reverse((_, i))) = frontier.pop() {
+        if !settled.insert(i) {
+            continue;
+        }
+        let node = paths.nodes[i].clone();
+        if goal(&node) {
+            return (paths, Some(i));
+        }
+        let cost = paths.best[i].0;
+        for (next, edge) in neighbors(&node) {
+            let (j, improved) = paths.offer(next, cost + edge, i, |new, old| new < old);
+            if improved && !settled.contains(&j) {
+                let estimate = cost + edge + heuristic(&paths.nodes[j]);
+                frontier.push(Reverse((estimate, j)));
+            }
+        }
+    }
+    (paths, None)
+}
+
+/// Cheapest paths from `start` to every reachable node, given `(neighbor, cost)`
+/// edges with non-negative costs
+pub fn dijkstra_all<N, C, I>(start: N, neighbors: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, neighbors, |_| C::default(), |_| false).0
+}
+
+/// Cheapest path from `start` to the nearest node satisfying `goal`, and its cost
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbors, |_| C::default(), goal)
+}
+
+/// [`dijkstra`] guided by `heuristic`, an estimate of the remaining cost to a goal.
+///
+/// The estimate must never be too high, nor drop by more than an edge's cost along
+/// that edge; Manhattan distance on a grid of unit steps is fine.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, found) = search(start, neighbors, heuristic, goal);
+    let end = &paths.nodes[found?];
+    Some((paths.path_to(end)?, paths.cost(end)?))
+}
+
+/// Split `nodes` into groups that can reach each other, each in the order [`bfs`]
+/// finds them; `neighbors` should list edges both ways
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut components = vec![];
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+        let component: Vec<N> = bfs(node, &mut neighbors)
+            .iter()
+            .map(|(n, _)| n.clone())
+            .collect();
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+    components
+}
+
+/// Order `nodes` so that every node comes before its `successors`, which must all be
+/// among `nodes`.
+///
+/// Ties keep the order of `nodes`. On a cycle, returns one of the nodes on it.
+pub fn toposort<N, I>(nodes: &[N], mut successors: impl FnMut(&N) -> I) -> Result<Vec<N>, N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let index: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let edges: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|n| successors(n).into_iter().map(|s| index[&s]).collect())
+        .collect();
+    let mut incoming = vec![0; nodes.len()];
+    for &j in edges.iter().flatten() {
+        incoming[j] += 1;
+    }
+    // Lowest index first, so ties keep the order of `nodes`
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..nodes.len())
+        .filter(|&i| incoming[i] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = vec![];
+    while let Some(Reverse(i)) = ready.pop() {
+        order.push(nodes[i].clone());
+        for &j in &edges[i] {
+            incoming[j] -= 1;
+            if incoming[j] == 0 {
+                ready.push(Reverse(j));
+            }
+        }
+    }
+    if order.len() < nodes.len() {
+        // Whatever is left still has an edge in from something else that is left, so
+        // following those edges backwards has to go round a cycle
+        let mut predecessors = vec![vec![]; nodes.len()];
+        for (i, next) in edges.iter().enumerate() {
+            for &j in next {
+                predecessors[j].push(i);
+            }
+        }
+        let mut i = (0..nodes.len()).find(|&i| incoming[i] > 0).unwrap();
+        let mut seen = HashSet::new();
+        while seen.insert(i) {
+            i = *predecessors[i].iter().find(|&&j| incoming[j] > 0).unwrap();
+        }
+        return Err(nodes[i].clone());
+    }
+    Ok(order)
+}
+
+/// Where the sequence `start`, `next(start)`, `next(next(start))`, ... starts repeating:
+/// `(mu, lambda)` for a first repeated element at index `mu` and a cycle of length `lambda`.
+///
+/// Uses Brent's algorithm, so only a couple of elements are kept at a time.
+pub fn find_cycle<N: Clone + Eq>(start: N, mut next: impl FnMut(&N) -> N) -> (usize, usize) {
+    let (mut power, mut lambda) = (1, 1);
+    let mut tortoise = start.clone();
+    let mut hare = next(&start);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = next(&hare);
+        lambda += 1;
+    }
+
+    let (mut tortoise, mut hare) = (start.clone(), start);
+    for _ in 0..lambda {
+        hare = next(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        mu += 1;
+    }
+    (mu, lambda)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bfs_finds_fewest_edges_and_dfs_goes_deep_first() {
+        // 0 - 1 - 2 - 3 and 0 - 3 on a ring of 4
+        let ring = |n: &u8| [(n + 1) % 4, (n + 3) % 4];
+        let paths = bfs(0, ring);
+        assert_eq!(paths.cost(&2), Some(2));
+        assert_eq!(paths.cost(&3), Some(1));
+        assert_eq!(
+            paths.iter().map(|(_, d)| d).collect::<Vec<_>>(),
+            [0, 1, 1, 2]
+        );
+        assert_eq!(paths.path_to(&0), Some(vec![0]));
+        assert_eq!(paths.cost(&9), None);
+        assert_eq!(dfs(0, ring), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        // a 5x5 open grid where stepping right costs 1 and down costs 2
+        let moves = |&(x, y): &(u32, u32)| {
+            let mut next = vec![];
+            if x < 4 {
+                next.push(((x + 1, y), 1));
+            }
+            if y < 4 {
+                next.push(((x, y + 1), 2));
+            }
+            next
+        };
+        let goal = |&p: &(u32, u32)| p == (4, 4);
+        let (path, cost) = dijkstra((0, 0), moves, goal).unwrap();
+        assert_eq!((path.len(), cost), (9, 12));
+        let manhattan = |&(x, y): &(u32, u32)| (4 - x) + (4 - y);
+        assert_eq!(astar((0, 0), moves, manhattan, goal).unwrap().1, 12);
+        assert_eq!(dijkstra_all((0, 0), moves).cost(&(2, 3)), Some(8));
+        assert_eq!(dijkstra((4, 4), moves, |&p| p == (0, 0)), None);
+    }
+
+    #[test]
+    fn splits_components() {
+        let edges = |n: &u8| match n {
+            1 => vec![2],
+            2 => vec![1, 3],
+            3 => vec![2],
+            4 => vec![],
+            5 => vec![6],
+            _ => vec![5],
+        };
+        assert_eq!(
+            connected_components(1..=6, edges),
+            [vec![1, 2, 3], vec![4], vec![5, 6]]
+        );
+    }
+
+    #[test]
+    fn sorts_topologically_or_finds_a_cycle() {
+        let depends = |n: &char| match n {
+            'a' => vec!['b', 'c'],
+            'b' => vec!['d'],
+            'c' => vec!['d'],
+            _ => vec![],
+        };
+        assert_eq!(
+            toposort(&['d', 'c', 'b', 'a'], depends),
+            Ok(vec!['a', 'c', 'b', 'd'])
+        );
+        let looping = |n: &char| match n {
+            'a' => vec!['b'],
+            'b' => vec!['c'],
+            _ => vec!['b'],
+        };
+        let on_cycle = toposort(&['a', 'b', 'c'], looping).unwrap_err();
+        assert!(on_cycle == 'b' || on_cycle == 'c');
+    }
+
+    #[test]
+    fn finds_where_a_sequence_cycles() {
+        // 0 1 2 3 4 5 6 | 3 4 5 6 ...
+        assert_eq!(find_cycle(0, |&n| if n == 6 { 3 } else { n + 1 }), (3, 4));
+        assert_eq!(find_cycle(7, |&n| n), (0, 1));
+    }
+}
@@ -0,0 +1,425 @@
+//! Rectangular grids of cells, as most map-shaped puzzles come.
+//!
+//! ```
+//! use advent23::grid::{Direction, Grid, Point};
+//!
+//! let grid = Grid::parse("467.\n..*.\n.35.")?;
+//! let gear = grid.find_all(|&c| c == '*').next().unwrap();
+//! assert_eq!(gear, Point::new(2, 1));
+//! assert_eq!(grid.neighbors4(gear).count(), 4);
+//! assert_eq!(grid.neighbors8(gear).filter(|&p| grid[p].is_ascii_digit()).count(), 4);
+//! assert_eq!(grid.step(gear, Direction::North), Some(Point::new(2, 0)));
+//! # Ok::<(), advent23::ParseError>(())
+//! ```
+use crate::error::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell's position: `x` counts columns from the left, `y` rows from the top
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// One step towards `direction`, unless that would leave the first quadrant
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        let (dx, dy) = direction.offset();
+        Some(Self {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+/// A compass direction, with north towards row 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// North, east, south and west
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight, clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// `(dx, dy)` of one step this way
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        let i = Self::ALL.iter().position(|&d| d == self).unwrap();
+        Self::ALL[(i + eighths) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+}
+
+/// A `width` by `height` grid, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// One cell per character, one row per line
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Self::parse_with(s, Some)
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `value`
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// One cell per character, one row per line, with `cell` turning characters into cells.
+    ///
+    /// Characters `cell` returns `None` for are reported as errors, as are empty input and
+    /// lines of differing lengths.
+    pub fn parse_with(
+        s: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (y, line) in s.lines().enumerate() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::in_line("unexpected character", line, &line[i..i + c.len_utf8()])
+                        .on_line(y + 1)
+                })?;
+                cells.push(value);
+            }
+            let row = cells.len() - before;
+            if *width.get_or_insert(row) != row {
+                return Err(
+                    ParseError::new("row width differs from the first row", line).on_line(y + 1),
+                );
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::new("empty grid", s)),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.y * self.width + p.x])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    /// Replace the cell at `p`, returning what was there, or `None` if `p` is outside the grid
+    pub fn set(&mut self, p: Point, value: T) -> Option<T> {
+        self.get_mut(p).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// One step from `p` towards `direction`, if that is still inside the grid
+    pub fn step(&self, p: Point, direction: Direction) -> Option<Point> {
+        p.step(direction).filter(|&q| self.contains(q))
+    }
+
+    /// Every point, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i % width, i / width))
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // not `chunks`, which can't make the empty rows of a zero-width grid
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The up to four points directly above, right of, below and left of `p`
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |d| self.step(p, d))
+    }
+
+    /// The up to eight points around `p`, diagonals included
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(p, d))
+    }
+
+    /// Points of every cell matching `predicate`, row by row
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    /// A grid of the same shape with `f` applied to every cell
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Build a `width` by `height` grid whose cell at `p` is taken from `self` at `source(p)`
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|i| self[source(Point::new(i % width, i / width))].clone())
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |p| Point::new(p.y, p.x))
+    }
+
+    /// A quarter turn clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        self.rearranged(self.height, self.width, |p| Point::new(p.y, h - 1 - p.x))
+    }
+
+    /// A quarter turn anticlockwise
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        self.rearranged(self.height, self.width, |p| Point::new(w - 1 - p.y, p.x))
+    }
+
+    /// Mirrored left to right
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        self.rearranged(self.width, self.height, |p| Point::new(w - 1 - p.x, p.y))
+    }
+
+    /// Mirrored top to bottom
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        self.rearranged(self.width, self.height, |p| Point::new(p.x, h - 1 - p.y))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", p, width, height))
+    }
+}
+
+/// Each row on its own line, cells written side by side
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_ragged_and_empty_grids() {
+        let e = Grid::parse("ab\nabc").unwrap_err();
+        assert_eq!(
+            (e.line, e.message.as_str()),
+            (2, "row width differs from the first row")
+        );
+        assert!(Grid::parse("").is_err());
+        let e = Grid::parse_with("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn zero_width_grids_have_empty_rows() {
+        let grid = Grid::new(0, 2, 'x');
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.to_string(), "\n");
+        assert_eq!(grid.transpose().rows().count(), 0);
+    }
+
+    #[test]
+    fn gets_and_sets_within_bounds() {
+        let mut grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.set(Point::new(2, 1), 7), Some(0));
+        assert_eq!(grid.set(Point::new(3, 0), 7), None);
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&7));
+        assert_eq!(grid.get(Point::new(0, 2)), None);
+        assert_eq!(grid.to_string(), "000\n007");
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [0, 7]);
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbors8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors4(Point::new(2, 1)).count(), 3);
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = Grid::parse("abc\ndef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn directions_turn() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::West.turn_left(), Direction::South);
+        assert_eq!(Direction::NorthEast.opposite(), Direction::SouthWest);
+    }
+}
@@ -0,0 +1,312 @@
+//! Sets of numbers kept as ranges rather than one by one.
+//!
+//! Intervals are half-open, like `start..end`. An [`IntervalSet`] keeps its
+//! intervals sorted and merged, and a [`RangeMap`] shifts whole sets at once:
+//!
+//! ```
+//! use advent23::intervals::{Interval, IntervalSet, RangeMap};
+//!
+//! let seeds: IntervalSet<u64> = [Interval::new(79, 93), Interval::new(55, 68)].into_iter().collect();
+//! let mut seed_to_soil = RangeMap::new();
+//! seed_to_soil.insert(Interval::new(98, 100), 50);
+//! seed_to_soil.insert(Interval::new(50, 98), 52);
+//! let soil = seed_to_soil.map_set(&seeds);
+//! assert_eq!(soil.intervals(), [Interval::new(57, 70), Interval::new(81, 95)]);
+//! assert_eq!(soil.min(), Some(57));
+//! ```
+use std::fmt;
+use std::ops::{Add, Range, Sub};
+
+/// The values from `start` up to but not including `end`
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values in both, if there are any
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let overlap = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+}
+
+impl<T: Copy + Ord + Default + Sub<Output = T>> Interval<T> {
+    /// How many values there are, for integer `T`
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::default()
+        } else {
+            self.end - self.start
+        }
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}..{:?}", self.start, self.end)
+    }
+}
+
+/// A set of values, stored as sorted intervals that neither overlap nor touch
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+
+    /// Sort `intervals`, drop empty ones, and merge any that overlap or touch
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Vec<Interval<T>> {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort();
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        merged
+    }
+
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals.iter().any(|i| i.contains(value))
+    }
+
+    /// The smallest value in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        self.intervals.push(interval);
+        self.intervals = Self::normalize(std::mem::take(&mut self.intervals));
+    }
+
+    /// Values in either set
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    /// Values in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            intervals.extend(a.intersection(&b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Values in this set but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut cuts = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            while let Some(cut) = cuts.peek() {
+                if cut.end <= rest.start {
+                    cuts.next();
+                    continue;
+                }
+                if cut.start >= rest.end {
+                    break;
+                }
+                if cut.start > rest.start {
+                    intervals.push(Interval::new(rest.start, cut.start));
+                }
+                rest.start = cut.end;
+                if rest.is_empty() {
+                    break;
+                }
+                cuts.next();
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval]).into_iter().collect()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self {
+            intervals: Self::normalize(iter.into_iter().collect()),
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+/// A piecewise-linear map: values in each source interval are shifted so that the
+/// interval starts at its destination, and values outside them all map to themselves.
+///
+/// Where source intervals overlap, the one inserted first wins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    pieces: Vec<(Interval<T>, T)>,
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> RangeMap<T> {
+    pub fn new() -> Self {
+        Self { pieces: vec![] }
+    }
+
+    /// Map `source` onto the interval of the same length starting at `destination`
+    pub fn insert(&mut self, source: Interval<T>, destination: T) {
+        self.pieces.push((source, destination));
+    }
+
+    /// Each source interval and where it starts mapping to, in the order they were inserted
+    pub fn pieces(&self) -> impl Iterator<Item = (Interval<T>, T)> + '_ {
+        self.pieces.iter().copied()
+    }
+
+    pub fn get(&self, value: T) -> T {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(value))
+            .map_or(value, |&(source, destination)| {
+                destination + (value - source.start)
+            })
+    }
+
+    /// Where every value in `set` maps to
+    pub fn map_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut unmapped = set.clone();
+        let mut mapped = vec![];
+        for &(source, destination) in &self.pieces {
+            let shift = |value: T| destination + (value - source.start);
+            let source = IntervalSet::from(source);
+            for hit in unmapped.intersection(&source).intervals() {
+                mapped.push(Interval::new(shift(hit.start), shift(hit.end)));
+            }
+            unmapped = unmapped.difference(&source);
+        }
+        mapped.extend(unmapped.intervals);
+        mapped.into_iter().collect()
+    }
+}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(s, e)| Interval::new(s, e))
+            .collect()
+    }
+
+    #[test]
+    fn normalizes_overlapping_and_touching_intervals() {
+        let s = set(&[(5, 8), (1, 3), (3, 4), (7, 10), (12, 12)]);
+        assert_eq!(s, set(&[(1, 4), (5, 10)]));
+        assert!(s.contains(9) && !s.contains(10) && !s.contains(4));
+        assert_eq!(Interval::new(5, 3).len(), 0);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(
+            a.difference(&set(&[(2, 4), (6, 8)])),
+            set(&[(0, 2), (4, 6), (8, 10), (20, 30)])
+        );
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    fn maps_values_and_sets() {
+        let mut map = RangeMap::new();
+        map.insert(Interval::new(10, 20), 100);
+        map.insert(Interval::new(15, 30), 0);
+        assert_eq!(map.get(9), 9);
+        assert_eq!(map.get(10), 100);
+        assert_eq!(map.get(19), 109);
+        assert_eq!(map.get(20), 5);
+        assert_eq!(map.get(30), 30);
+        assert_eq!(map.map_set(&set(&[(5, 25)])), set(&[(5, 10), (100, 110)]));
+        assert_eq!(
+            map.map_set(&set(&[(18, 35)])),
+            set(&[(5, 15), (30, 35), (108, 110)])
+        );
+    }
+}
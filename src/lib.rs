@@ -1,9 +1,44 @@
-pub mod vendor;
-
-pub fn parse_number_list<T: std::str::FromStr>(s: &str) -> Vec<T> {
-    s.split_whitespace()
-        .into_iter()
-        .map(|x| x.parse())
-        .flatten()
-        .collect()
+//! Advent of Code solutions.
+//!
+//! Every day implements [`solution::Solution`] and lives in its year's module
+//! under [`years`]. [`day`] looks one up by number in the default year, and
+//! [`day_in`] in any other; either solves puzzle input passed in as a string:
+//!
+//! ```
+//! let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+//! let day6 = advent23::day(6)?;
+//! assert_eq!(day6.part1(input)?.to_string(), "288");
+//! assert_eq!(day6.part2(input)?, advent23::Answer::Unsigned(71503));
+//! # Ok::<(), advent23::Error>(())
+//! ```
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod intervals;
+pub mod log;
+pub mod numtheory;
+pub mod parse;
+pub mod solution;
+pub mod years;
+
+/// 2023's days under the path they had before years were split out
+pub use years::y2023 as challenges;
+
+pub use error::{Error, ParseError, Result};
+pub use parse::parse_number_list;
+pub use solution::{Answer, Day, Part, Solution};
+
+/// Look up a day's solution by its number, in [`years::DEFAULT_YEAR`]
+pub fn day(day: u8) -> Result<&'static dyn Day> {
+    day_in(years::DEFAULT_YEAR, day)
+}
+
+/// Look up a day's solution by its year and number
+pub fn day_in(year: u16, day: u8) -> Result<&'static dyn Day> {
+    years::get(year, day).ok_or(Error::Unimplemented(day))
+}
+
+/// Every implemented day of [`years::DEFAULT_YEAR`], in order
+pub fn days() -> &'static [&'static dyn Day] {
+    years::days(years::DEFAULT_YEAR)
 }
@@ -0,0 +1,76 @@
+//! Leveled diagnostics for solvers, written to stderr.
+//!
+//! Nothing is printed unless the verbosity has been raised with
+//! [`set_verbosity`], which the CLI does for `-v` (debug) and `-vv` (trace):
+//!
+//! ```
+//! advent23::debug!("{} ranges left", 3);
+//! advent23::trace!("split {:?} into {:?}", (1, 9), [(1, 4), (5, 9)]);
+//! ```
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Intermediate results worth a look when an answer is wrong
+    Debug = 1,
+    /// Step-by-step detail, usually far too much to read in full
+    Trace = 2,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static COLOR: AtomicBool = AtomicBool::new(true);
+
+/// 0 for quiet, 1 to include [`Level::Debug`], 2 or more to include [`Level::Trace`]
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= level as u8
+}
+
+/// Whether [`paint`] adds ANSI colors; on unless turned off
+pub fn set_color(color: bool) {
+    COLOR.store(color, Ordering::Relaxed);
+}
+
+/// `text` in the ANSI style `style` (e.g. `"1;32"` for bold green), or as-is with color off
+pub fn paint(style: &str, text: impl fmt::Display) -> String {
+    if COLOR.load(Ordering::Relaxed) {
+        format!("\x1b[{}m{}\x1b[0m", style, text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, args: fmt::Arguments) {
+    let tag = match level {
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    };
+    // A single write per message, so messages from days running side by side
+    // don't interleave
+    eprintln!("{}: {}", tag, args);
+}
+
+/// Log a message at [`Level::Debug`]
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+/// Log a message at [`Level::Trace`]
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
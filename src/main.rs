@@ -1,7 +1,22 @@
-use clap::Parser;
+use advent23::error::{Error, Result};
+use advent23::solution::{Day, Part};
+use advent23::years::{self, LAST_DAY, YEARS};
+use clap::{ArgMatches, Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use output::{print_json, Format, Record};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process;
 
-mod challenges;
-pub mod vendor;
+mod bench;
+mod config;
+mod fetch;
+mod new_day;
+mod output;
+mod runner;
+mod stats;
+mod submit;
+mod verify;
+mod watch;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -9,80 +24,286 @@ struct Cli {
     /// Challenge Day
     #[command(subcommand)]
     command: Command,
+
+    #[command(flatten)]
+    config: config::Overrides,
+
+    /// Show solver diagnostics on stderr; repeat for more detail
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
 }
 
 #[derive(clap::Subcommand, Debug)]
 enum Command {
-    /// sum first and last digit of each line ("Trebuchet!?")
-    Day1(challenges::day01::Args),
-    /// multidimensonal data processing ("Cube Conundrum")
-    Day2(challenges::day02::Args),
-    /// 2D array traversal ("Gear Ratios")
-    Day3(challenges::day03::Args),
-    /// set comparison ("Scratchcards")
-    Day4(challenges::day04::Args),
-    Day5(challenges::day05::Args),
-    Day6(challenges::day06::Args),
-    Day7(challenges::day07::Args),
-    Day8(challenges::day08::Args),
-    Day9(challenges::day09::Args),
-    Day10(challenges::day10::Args),
-    Day11(challenges::day11::Args),
-    Day12(challenges::day12::Args),
-    Day13(challenges::day13::Args),
-    Day14(challenges::day14::Args),
-    Day15(challenges::day15::Args),
-    Day16(challenges::day16::Args),
-    Day17(challenges::day17::Args),
-    Day18(challenges::day18::Args),
-    Day19(challenges::day19::Args),
-    Day20(challenges::day20::Args),
-    Day21(challenges::day21::Args),
-    Day22(challenges::day22::Args),
-    Day23(challenges::day23::Args),
-    Day24(challenges::day24::Args),
-    Day25(challenges::day25::Args),
-    Day26(challenges::day26::Args),
-    Day27(challenges::day27::Args),
-    Day28(challenges::day28::Args),
-    Day29(challenges::day29::Args),
-    Day30(challenges::day30::Args),
-    Day31(challenges::day31::Args),
+    #[command(flatten)]
+    Day(DayCommand),
+    /// Run every implemented day, or just the ones listed, in parallel and print a table
+    /// of answers and timings
+    #[command(visible_alias = "run")]
+    All(runner::RunAllArgs),
+    /// Check every day against the answers recorded in inputs/YYYY/dayN/answers.toml
+    Verify,
+    /// Time parsing and solving a day over many iterations
+    Bench(bench::BenchArgs),
+    /// Download a day's puzzle input into inputs/YYYY/dayN/input.txt
+    Fetch(fetch::FetchArgs),
+    /// Solve one part of a day and submit the answer
+    Submit(submit::SubmitArgs),
+    /// Scaffold a new day's module and inputs and register it
+    NewDay(new_day::NewDayArgs),
+    /// Print a completion script for a shell to stdout
+    Completions { shell: clap_complete::Shell },
+    /// Print a man page in roff format to stdout
+    Manpage,
+}
+
+/// Arguments shared by every `dayN` subcommand
+#[derive(clap::Args, Debug)]
+struct RunArgs {
+    #[command(flatten)]
+    input: runner::InputArgs,
+
+    #[clap(long, short, action)]
+    part2: bool,
+
+    /// Parse the input once and solve both parts
+    #[clap(long, short, action, conflicts_with = "part2")]
+    both: bool,
+
+    /// Describe the parsed input instead of solving it
+    #[clap(long, short, action)]
+    describe: bool,
+
+    /// Re-run whenever the input file changes
+    #[clap(long, short, action, conflicts_with = "describe")]
+    watch: bool,
+
+    /// Time parsing and each part separately and count their allocations
+    #[clap(long, action, conflicts_with_all = ["describe", "watch"])]
+    stats: bool,
+
+    /// Another file to watch; may be given more than once
+    #[clap(long, requires = "watch")]
+    watch_path: Vec<PathBuf>,
+}
+
+impl RunArgs {
+    /// The parts to solve, in order
+    fn parts(&self) -> Vec<Part> {
+        match (self.both, self.part2) {
+            (true, _) => vec![Part::One, Part::Two],
+            (false, true) => vec![Part::Two],
+            (false, false) => vec![Part::One],
+        }
+    }
+}
+
+/// One `dayN` subcommand per day, generated from the challenge registry
+#[derive(Debug)]
+struct DayCommand {
+    day: u8,
+    args: RunArgs,
+}
+
+fn day_from_name(name: &str) -> Option<u8> {
+    let day: u8 = name.strip_prefix("day")?.parse().ok()?;
+    (1..=LAST_DAY).contains(&day).then_some(day)
+}
+
+/// A day's title, or its title in each year if there are several
+fn about(day: u8) -> String {
+    let titles: Vec<(u16, &str)> = YEARS
+        .iter()
+        .filter_map(|y| Some((y.year, years::get(y.year, day)?.title())))
+        .collect();
+    match titles[..] {
+        [] => String::from("not yet implemented"),
+        [(_, title)] if YEARS.len() == 1 => title.to_string(),
+        _ => titles
+            .iter()
+            .map(|(year, title)| format!("{}: {}", year, title))
+            .collect::<Vec<_>>()
+            .join("; "),
+    }
+}
+
+impl FromArgMatches for DayCommand {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        match matches.subcommand() {
+            Some((name, sub_matches)) => match day_from_name(name) {
+                Some(day) => Ok(Self {
+                    day,
+                    args: RunArgs::from_arg_matches(sub_matches)?,
+                }),
+                None => Err(clap::Error::raw(
+                    clap::error::ErrorKind::InvalidSubcommand,
+                    format!("unknown day: {}", name),
+                )),
+            },
+            None => Err(clap::Error::raw(
+                clap::error::ErrorKind::MissingSubcommand,
+                "a day is required",
+            )),
+        }
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Subcommand for DayCommand {
+    fn augment_subcommands(mut cmd: clap::Command) -> clap::Command {
+        for day in 1..=LAST_DAY {
+            cmd = cmd.subcommand(
+                RunArgs::augment_args(clap::Command::new(format!("day{}", day))).about(about(day)),
+            );
+        }
+        cmd
+    }
+
+    fn augment_subcommands_for_update(cmd: clap::Command) -> clap::Command {
+        Self::augment_subcommands(cmd)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        day_from_name(name).is_some()
+    }
+}
+
+/// Solve the part(s) `args` asks for; fails only if `--both` couldn't parse the input
+fn solve_parts(solution: &dyn Day, input: &str, args: &RunArgs) -> Result<runner::PartResults> {
+    if args.both {
+        return runner::timed_both(solution, input).map(Vec::from);
+    }
+    let part = if args.part2 { Part::Two } else { Part::One };
+    let (answer, time) = runner::timed(solution, input, part);
+    Ok(vec![(part, answer, time)])
+}
+
+fn watch_day(year: u16, day: u8, args: &RunArgs, format: Format) -> Result<()> {
+    let solution = advent23::day_in(year, day)?;
+    let mut paths = args.watch_path.clone();
+    paths.extend(args.input.watchable(year, day).map(PathBuf::from));
+    if paths.is_empty() {
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "--watch needs a file to watch; use --watch-path with stdin or --input-text",
+            )
+            .exit();
+    }
+    watch::watch(day, &paths, format, || {
+        let (file, input) = args.input.load(year, day)?;
+        let results = solve_parts(solution, &input, args).map_err(|e| e.in_file(&file))?;
+        Ok((file, results))
+    })
+}
+
+fn run_day(year: u16, day: u8, args: &RunArgs, format: Format) -> Result<()> {
+    if args.watch {
+        return watch_day(year, day, args, format);
+    }
+    let solution = advent23::day_in(year, day)?;
+    let (file, input) = args.input.load(year, day)?;
+    if args.describe {
+        let description = solution.describe(&input).map_err(|e| e.in_file(&file))?;
+        match (format, description) {
+            (Format::Json, description) => print_json(&serde_json::json!({
+                "day": day,
+                "input": file,
+                "description": description,
+            })),
+            (Format::Plain, Some(description)) => print!("{}", description),
+            (Format::Plain, None) => println!("day {} has nothing to describe", day),
+        }
+        return Ok(());
+    }
+    let solved = if args.stats {
+        stats::run_measured(solution, &input, &args.parts()).map(|(r, phases)| (r, Some(phases)))
+    } else {
+        solve_parts(solution, &input, args).map(|r| (r, None))
+    };
+    let (results, phases) = match solved {
+        Ok(solved) => solved,
+        Err(e) => {
+            let e = e.in_file(&file);
+            if format == Format::Json {
+                for part in args.parts() {
+                    print_json(&Record::failed(day, part, &file, &e));
+                }
+            }
+            return Err(e);
+        }
+    };
+    let mut first_error = None;
+    for (part, answer, time) in results {
+        let answer = answer.map_err(|e| e.in_file(&file));
+        match (format, &answer) {
+            (Format::Json, _) => print_json(&Record::new(day, part, &file, &answer, time)),
+            (Format::Plain, Ok(answer)) => println!("{}", answer),
+            (Format::Plain, Err(e)) if args.both => eprintln!("part {}: {}", part, e),
+            (Format::Plain, Err(_)) => {}
+        }
+        if let Err(e) = answer {
+            first_error.get_or_insert(e);
+        }
+    }
+    if let Some(phases) = phases {
+        stats::print_phases(day, &phases, format);
+    }
+    first_error.map_or(Ok(()), Err)
 }
 
 fn main() {
     let cli = Cli::parse();
-    match &cli.command {
-        Command::Day1(args) => challenges::day01::entrypoint(args),
-        Command::Day2(args) => challenges::day02::entrypoint(args),
-        Command::Day3(args) => challenges::day03::entrypoint(args),
-        Command::Day4(args) => challenges::day04::entrypoint(args),
-        Command::Day5(args) => challenges::day05::entrypoint(args),
-        Command::Day6(args) => challenges::day06::entrypoint(args),
-        Command::Day7(args) => challenges::day07::entrypoint(args),
-        Command::Day8(args) => challenges::day08::entrypoint(args),
-        Command::Day9(args) => challenges::day09::entrypoint(args),
-        Command::Day10(args) => challenges::day10::entrypoint(args),
-        Command::Day11(args) => challenges::day11::entrypoint(args),
-        Command::Day12(args) => challenges::day12::entrypoint(args),
-        Command::Day13(args) => challenges::day13::entrypoint(args),
-        Command::Day14(args) => challenges::day14::entrypoint(args),
-        Command::Day15(args) => challenges::day15::entrypoint(args),
-        Command::Day16(args) => challenges::day16::entrypoint(args),
-        Command::Day17(args) => challenges::day17::entrypoint(args),
-        Command::Day18(args) => challenges::day18::entrypoint(args),
-        Command::Day19(args) => challenges::day19::entrypoint(args),
-        Command::Day20(args) => challenges::day20::entrypoint(args),
-        Command::Day21(args) => challenges::day21::entrypoint(args),
-        Command::Day22(args) => challenges::day22::entrypoint(args),
-        Command::Day23(args) => challenges::day23::entrypoint(args),
-        Command::Day24(args) => challenges::day24::entrypoint(args),
-        Command::Day25(args) => challenges::day25::entrypoint(args),
-        Command::Day26(args) => challenges::day26::entrypoint(args),
-        Command::Day27(args) => challenges::day27::entrypoint(args),
-        Command::Day28(args) => challenges::day28::entrypoint(args),
-        Command::Day29(args) => challenges::day29::entrypoint(args),
-        Command::Day30(args) => challenges::day30::entrypoint(args),
-        Command::Day31(args) => challenges::day31::entrypoint(args),
+    advent23::log::set_verbosity(cli.verbose);
+    let settings = match config::init(&cli.config) {
+        Ok(settings) => settings,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(e.exit_code());
+        }
+    };
+    advent23::log::set_color(settings.color);
+    let (year, format) = (settings.year, settings.format);
+    let result = match &cli.command {
+        Command::Day(DayCommand { day, args }) => run_day(year, *day, args, format),
+        Command::All(args) => match runner::run_all(args, year, format) {
+            Ok(false) => process::exit(1),
+            result => result.map(|_| ()),
+        },
+        Command::Verify => {
+            if !verify::run_verify(year, format) {
+                process::exit(1);
+            }
+            Ok(())
+        }
+        Command::Bench(args) => bench::run_bench(args, year, format),
+        Command::Fetch(args) => fetch::run_fetch(args, year),
+        Command::NewDay(args) => new_day::run_new_day(args, year),
+        Command::Completions { shell } => {
+            // `generate` panics if it can't write, so write the script out ourselves
+            let mut script = vec![];
+            clap_complete::generate(*shell, &mut Cli::command(), "advent23", &mut script);
+            output::exit_on_broken_pipe(io::stdout().lock().write_all(&script));
+            Ok(())
+        }
+        Command::Manpage => {
+            let mut page = vec![];
+            clap_mangen::Man::new(Cli::command())
+                .render(&mut page)
+                .map_err(|e| Error::io("<manpage>", e))
+                .map(|_| output::exit_on_broken_pipe(io::stdout().lock().write_all(&page)))
+        }
+        Command::Submit(args) => match submit::run_submit(args, year, format) {
+            Ok(false) => process::exit(1),
+            result => result.map(|_| ()),
+        },
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(e.exit_code());
     }
 }
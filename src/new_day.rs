@@ -0,0 +1,188 @@
+use advent23::error::{Error, Result};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process;
+
+use crate::config;
+use crate::runner::input_dir;
+
+const MODULE_TEMPLATE: &str = include_str!("../templates/day.rs");
+const ANSWERS_TEMPLATE: &str = include_str!("../templates/answers.toml");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs");
+
+/// Where the year modules live, from the project root
+fn years_dir() -> String {
+    config::settings()
+        .in_project("src/years")
+        .display()
+        .to_string()
+}
+
+#[derive(clap::Args, Debug)]
+pub struct NewDayArgs {
+    /// Day to create
+    #[clap(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Puzzle title shown in `--help` and `all`
+    #[clap(long, short)]
+    title: Option<String>,
+}
+
+/// Add module `name` to the `pub mod <prefix>...;` declarations in `mod_rs`, then
+/// rewrite the static starting with `head` to list every such module via `entry`.
+/// Both stay in order.
+fn register_module(
+    mod_rs: &str,
+    prefix: &str,
+    name: &str,
+    head: &str,
+    entry: impl Fn(&str) -> String,
+) -> String {
+    let declaration = format!("pub mod {}", prefix);
+    let mut lines: Vec<String> = mod_rs.lines().map(String::from).collect();
+    let mut modules: Vec<String> = lines
+        .iter()
+        .filter_map(|l| l.strip_prefix(&declaration)?.strip_suffix(';'))
+        .map(|rest| format!("{}{}", prefix, rest))
+        .collect();
+    let at = match lines.iter().position(|l| l.starts_with(&declaration)) {
+        Some(at) => at,
+        None => {
+            // straight after the imports, in a paragraph of their own
+            let at = lines.iter().position(|l| l.is_empty()).unwrap_or(0) + 1;
+            lines.insert(at, String::new());
+            at
+        }
+    };
+    lines.retain(|l| !l.starts_with(&declaration));
+    modules.push(name.to_string());
+    modules.sort();
+    modules.dedup();
+    for (i, module) in modules.iter().enumerate() {
+        lines.insert(at + i, format!("pub mod {};", module));
+    }
+
+    let start = lines.iter().position(|l| l.starts_with(head)).unwrap();
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l.ends_with("];"))
+            .unwrap();
+    let mut listing = vec![format!("{} = &[", head)];
+    listing.extend(modules.iter().map(|m| entry(m)));
+    listing.push(String::from("];"));
+    lines.splice(start..=end, listing);
+
+    lines.join("\n") + "\n"
+}
+
+/// Add `dayNN` to the module list and `DAYS` in a year's `mod.rs`
+pub fn register_day(mod_rs: &str, day: u8) -> String {
+    let name = format!("day{:02}", day);
+    register_module(mod_rs, "day", &name, "pub static DAYS: &[&dyn Day]", |m| {
+        format!("    &{}::Day{},", m, &m[3..])
+    })
+}
+
+/// Add `yYYYY` to the module list and `YEARS` in `years/mod.rs`
+pub fn register_year(mod_rs: &str, year: u16) -> String {
+    let name = format!("y{}", year);
+    register_module(mod_rs, "y", &name, "pub static YEARS: &[Year]", |m| {
+        format!(
+            "    Year {{\n        year: {},\n        days: {}::DAYS,\n    }},",
+            &m[1..],
+            m
+        )
+    })
+}
+
+/// Read, edit and write back a file
+fn rewrite(path: &str, edit: impl FnOnce(&str) -> String) -> Result<()> {
+    let contents = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+    write(path, &edit(&contents))
+}
+
+pub fn run_new_day(args: &NewDayArgs, year: u16) -> Result<()> {
+    let day = args.day;
+    let years_dir = years_dir();
+    let year_dir = format!("{}/y{}", years_dir, year);
+    let module = format!("{}/day{:02}.rs", year_dir, day);
+    if advent23::day_in(year, day).is_ok() || Path::new(&module).exists() {
+        let exists = io::Error::new(io::ErrorKind::AlreadyExists, "day already exists");
+        return Err(Error::io(&module, exists));
+    }
+    let year_mod_rs = format!("{}/mod.rs", year_dir);
+    if !Path::new(&year_mod_rs).exists() {
+        write(
+            &year_mod_rs,
+            &YEAR_TEMPLATE.replace("{{YEAR}}", &year.to_string()),
+        )?;
+        rewrite(&format!("{}/mod.rs", years_dir), |s| register_year(s, year))?;
+    }
+
+    let title = args.title.clone().unwrap_or_else(|| format!("Day {}", day));
+    let source = MODULE_TEMPLATE
+        .replace("{{PADDED}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{TITLE}}", &title.replace('"', "\\\""));
+    write(&module, &source)?;
+    rewrite(&year_mod_rs, |s| register_day(s, day))?;
+
+    // Tidy up what we generated if rustfmt is around; it compiles either way
+    let _ = process::Command::new("rustfmt")
+        .args(["--edition", "2021", &module, &year_mod_rs])
+        .status();
+
+    let dir = input_dir(year, day);
+    for (name, contents) in [("sample.txt", ""), ("answers.toml", ANSWERS_TEMPLATE)] {
+        let path = dir.join(name).display().to_string();
+        if !Path::new(&path).exists() {
+            write(&path, contents)?;
+        }
+    }
+    eprintln!(
+        "created {}; paste the example into {}",
+        module,
+        dir.join("sample.txt").display()
+    );
+    Ok(())
+}
+
+fn write(path: &str, contents: &str) -> Result<()> {
+    let parent = Path::new(path).parent().unwrap();
+    fs::create_dir_all(parent)
+        .and_then(|_| fs::write(path, contents))
+        .map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_days_in_order() {
+        let mod_rs = "use crate::solution::Day;\n\npub mod day01;\npub mod day03;\n\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n    &day03::Day03,\n];\n";
+        assert_eq!(
+            register_day(mod_rs, 2),
+            "use crate::solution::Day;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n    &day02::Day02,\n    &day03::Day03,\n];\n"
+        );
+        assert!(register_day(mod_rs, 11).contains("pub mod day03;\npub mod day11;\n"));
+        assert!(register_day(mod_rs, 11).contains("    &day03::Day03,\n    &day11::Day11,\n];"));
+    }
+
+    #[test]
+    fn registers_first_day_of_a_new_year() {
+        let year = YEAR_TEMPLATE.replace("{{YEAR}}", "2024");
+        assert_eq!(
+            register_day(&year, 1),
+            "use crate::solution::Day;\n\npub mod day01;\n\n/// Every implemented day of 2024, in order\npub static DAYS: &[&dyn Day] = &[\n    &day01::Day01,\n];\n"
+        );
+        let years = "use crate::solution::Day;\n\npub mod y2023;\n\npub static YEARS: &[Year] = &[Year {\n    year: 2023,\n    days: y2023::DAYS,\n}];\n";
+        assert_eq!(
+            register_year(years, 2024),
+            "use crate::solution::Day;\n\npub mod y2023;\npub mod y2024;\n\npub static YEARS: &[Year] = &[\n    Year {\n        year: 2023,\n        days: y2023::DAYS,\n    },\n    Year {\n        year: 2024,\n        days: y2024::DAYS,\n    },\n];\n"
+        );
+    }
+}
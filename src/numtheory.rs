@@ -0,0 +1,210 @@
+//! Integer number theory that doesn't overflow quietly.
+//!
+//! Everything works in `u128`/`i128`, and anything that could still overflow
+//! returns `None` rather than wrapping or panicking. The one panic is a modulus
+//! of 0 passed to [`mul_mod`] or [`mod_pow`]:
+//!
+//! ```
+//! use advent23::numtheory::{checked_lcm, crt, lcm_all};
+//!
+//! assert_eq!(lcm_all([4u64, 6, 10]), Some(60));
+//! assert_eq!(checked_lcm([u64::MAX, u64::MAX - 1]), None);
+//! // x = 2 (mod 6) and x = 8 (mod 10), with moduli that share a factor
+//! assert_eq!(crt([(2, 6), (8, 10)]), Some((8, 30)));
+//! ```
+
+/// Greatest common divisor; `gcd(0, 0)` is 0
+pub fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, or `None` if it doesn't fit; `lcm(0, n)` is 0
+pub fn lcm(a: u128, b: u128) -> Option<u128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Greatest common divisor of every number, 0 for none
+pub fn gcd_all<T: Into<u128>>(numbers: impl IntoIterator<Item = T>) -> u128 {
+    numbers.into_iter().fold(0, |acc, n| gcd(acc, n.into()))
+}
+
+/// Least common multiple of every number, 1 for none, or `None` if it doesn't fit
+pub fn lcm_all<T: Into<u128>>(numbers: impl IntoIterator<Item = T>) -> Option<u128> {
+    numbers.into_iter().try_fold(1, |acc, n| lcm(acc, n.into()))
+}
+
+/// [`lcm_all`], but `None` unless the result also fits back into `T`
+pub fn checked_lcm<T: Into<u128> + TryFrom<u128>>(
+    numbers: impl IntoIterator<Item = T>,
+) -> Option<T> {
+    T::try_from(lcm_all(numbers)?).ok()
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g`, where `g` is the non-negative gcd of `a` and `b`
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` with `a * x = 1 (mod modulus)`, if `a` and `modulus` are coprime
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// `a * b % modulus`, without overflowing however large the operands.
+///
+/// Panics if `modulus` is 0.
+pub fn mul_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    assert!(modulus != 0, "mul_mod: modulus must not be 0");
+    let (mut a, mut b) = (a % modulus, b % modulus);
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // Double and add; each step stays below 2 * modulus
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, modulus);
+        }
+        a = add_mod(a, a, modulus);
+        b >>= 1;
+    }
+    result
+}
+
+/// `(a + b) % modulus` for `a` and `b` already below `modulus`
+fn add_mod(a: u128, b: u128, modulus: u128) -> u128 {
+    if a >= modulus - b {
+        a - (modulus - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base` to the power `exp`, modulo `modulus`.
+///
+/// Panics if `modulus` is 0.
+pub fn mod_pow(base: u128, mut exp: u128, modulus: u128) -> u128 {
+    assert!(modulus != 0, "mod_pow: modulus must not be 0");
+    if modulus == 1 {
+        return 0;
+    }
+    let mut base = base % modulus;
+    let mut result = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Solve the system `x = residue (mod modulus)` for every `(residue, modulus)` pair.
+///
+/// Moduli needn't be coprime. Returns `(x, m)` where every solution is `x` plus a
+/// multiple of `m`, the lcm of the moduli, and `0 <= x < m`; `None` if the congruences
+/// contradict each other, a modulus isn't positive, or `m` doesn't fit.
+pub fn crt(congruences: impl IntoIterator<Item = (i128, i128)>) -> Option<(i128, i128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, m), (residue, modulus)| {
+            if modulus <= 0 {
+                return None;
+            }
+            let residue = residue.rem_euclid(modulus);
+            let (g, p, _) = extended_gcd(m, modulus);
+            let difference = residue - x;
+            if difference % g != 0 {
+                return None;
+            }
+            // x + m * t solves both once t = (difference / g) * p (mod modulus / g)
+            let step = (modulus / g) as u128;
+            let t = mul_mod(
+                (difference / g).rem_euclid(step as i128) as u128,
+                p.rem_euclid(step as i128) as u128,
+                step,
+            );
+            let lcm = i128::try_from(lcm(m as u128, modulus as u128)?).ok()?;
+            let offset = mul_mod(m as u128, t, lcm as u128) as i128;
+            Some((x.checked_add(offset)?.rem_euclid(lcm), lcm))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lcm_and_gcd_of_many() {
+        assert_eq!(gcd_all([12u32, 18, 30]), 6);
+        assert_eq!(gcd_all(Vec::<u8>::new()), 0);
+        assert_eq!(lcm_all(Vec::<u8>::new()), Some(1));
+        assert_eq!(lcm_all([0u8, 5]), Some(0));
+        // a * b overflows u64 before the division, the lcm itself doesn't
+        let big = 1u64 << 40;
+        assert_eq!(checked_lcm([big, big * 3]), Some(big * 3));
+        assert_eq!(checked_lcm([u32::MAX, u32::MAX - 1]), None);
+        assert_eq!(lcm_all([u128::MAX, u128::MAX - 1]), None);
+    }
+
+    #[test]
+    fn extended_euclid_and_inverses() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn modular_powers_of_large_numbers() {
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        let p = (1u128 << 127) - 1;
+        // Fermat: a^(p-1) = 1 (mod p) for prime p
+        assert_eq!(mod_pow(3, p - 1, p), 1);
+        assert_eq!(mul_mod(p - 1, p - 1, p), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must not be 0")]
+    fn zero_modulus_is_refused() {
+        mod_pow(2, 3, 0);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(0, 4), (2, 6)]), Some((8, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 0)]), None);
+    }
+}
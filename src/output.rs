@@ -0,0 +1,103 @@
+use advent23::error::{Error, Result};
+use advent23::solution::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io::{self, Write};
+use std::process;
+use std::time::Duration;
+
+/// How results are written to stdout
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Human-readable text and tables
+    #[default]
+    Plain,
+    /// One JSON object per line
+    Json,
+}
+
+/// Whether to use ANSI colors
+#[derive(clap::ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    /// Only when stdout is a terminal
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// The outcome of solving one part of one day, as emitted by `--format json`
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// Numbers are emitted as JSON numbers, anything else as a string
+    pub answer: Option<Value>,
+    pub time_ns: u64,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub diagnostics: Vec<String>,
+}
+
+impl Record {
+    pub fn new(day: u8, part: Part, input: &str, answer: &Result<Answer>, time: Duration) -> Self {
+        let (answer, diagnostics) = match answer {
+            Ok(answer) => (Some(answer_json(answer)), vec![]),
+            Err(e) => (None, vec![e.to_string()]),
+        };
+        Self {
+            day,
+            part: part_number(part),
+            input: input.to_string(),
+            answer,
+            time_ns: time.as_nanos() as u64,
+            diagnostics,
+        }
+    }
+
+    /// A part that couldn't be attempted at all, e.g. because its input didn't parse
+    pub fn failed(day: u8, part: Part, input: &str, error: &Error) -> Self {
+        Self {
+            day,
+            part: part_number(part),
+            input: input.to_string(),
+            answer: None,
+            time_ns: 0,
+            diagnostics: vec![error.to_string()],
+        }
+    }
+}
+
+pub fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+pub fn answer_json(answer: &Answer) -> Value {
+    match answer {
+        Answer::Unsigned(n) => Value::from(*n),
+        Answer::Signed(n) => Value::from(*n),
+        Answer::Text(s) => Value::from(s.as_str()),
+    }
+}
+
+/// Stop quietly if whatever stdout is piped into has stopped reading, e.g. `| head`.
+///
+/// Any other failure to write to stdout panics, as `println!` would.
+pub fn exit_on_broken_pipe(written: io::Result<()>) {
+    match written {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => process::exit(0),
+        Err(e) => panic!("failed printing to stdout: {}", e),
+    }
+}
+
+/// Print a value as a single line of JSON
+pub fn print_json<T: Serialize>(value: &T) {
+    let line = serde_json::to_string(value).unwrap();
+    exit_on_broken_pipe(writeln!(io::stdout().lock(), "{}", line));
+}
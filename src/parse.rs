@@ -0,0 +1,111 @@
+//! Strict helpers for pulling numbers out of puzzle input.
+//!
+//! Every token has to parse; the first one that doesn't is reported with its
+//! byte offset and column in the text it came from:
+//!
+//! ```
+//! use advent23::parse::{parse_n, parse_number_list};
+//!
+//! assert_eq!(parse_number_list::<u64>("79 14 55 13")?, [79, 14, 55, 13]);
+//! assert_eq!(parse_n::<3, u64>("50 98 2")?, [50, 98, 2]);
+//!
+//! let e = parse_number_list::<u64>("41 48 x3 86").unwrap_err();
+//! assert_eq!((e.text.as_str(), e.offset, e.column), ("x3", Some(6), 7));
+//! # Ok::<(), advent23::ParseError>(())
+//! ```
+//!
+//! Errors are positioned within the string they were given; when that is only
+//! part of a line, [`ParseError::within`] moves them into the whole line.
+use crate::error::ParseError;
+use std::str::FromStr;
+
+/// Parse `token`, a slice of `s`
+fn number<T: FromStr>(s: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::in_line("invalid number", s, token))
+}
+
+/// Whitespace-separated numbers
+pub fn parse_number_list<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    s.split_whitespace().map(|token| number(s, token)).collect()
+}
+
+/// Numbers separated by `separator`, with any whitespace around them ignored, e.g.
+/// `parse_separated(s, ",")` for `"1, 2,3"`
+pub fn parse_separated<T: FromStr>(s: &str, separator: &str) -> Result<Vec<T>, ParseError> {
+    if s.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    s.split(separator)
+        .map(|token| number(s, token.trim()))
+        .collect()
+}
+
+/// Every integer in `s`, with its sign, ignoring whatever text surrounds them
+pub fn parse_signed_ints<T: FromStr>(s: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = s.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        numbers.push(number(s, &s[start..i])?);
+    }
+    Ok(numbers)
+}
+
+/// Exactly `N` whitespace-separated numbers
+pub fn parse_n<const N: usize, T: FromStr>(s: &str) -> Result<[T; N], ParseError> {
+    let numbers: Vec<T> = parse_number_list(s)?;
+    let found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| ParseError::new(format!("expected {} numbers, found {}", N, found), s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_tokens_that_are_not_numbers() {
+        let e = parse_number_list::<u64>("Card 1: 41 48").unwrap_err();
+        assert_eq!(e.text, "Card");
+        assert_eq!((e.offset, e.column), (Some(0), 1));
+        assert!(parse_number_list::<u64>("1 -2").is_err());
+        assert_eq!(parse_number_list::<i64>("1 -2").unwrap(), [1, -2]);
+        assert_eq!(parse_number_list::<u8>(" ").unwrap(), []);
+    }
+
+    #[test]
+    fn splits_on_other_separators() {
+        assert_eq!(parse_separated::<u32>("3, 1,4", ",").unwrap(), [3, 1, 4]);
+        assert_eq!(parse_separated::<u32>("", ",").unwrap(), []);
+        let e = parse_separated::<u32>("3,,4", ",").unwrap_err();
+        assert_eq!(e.offset, Some(2));
+    }
+
+    #[test]
+    fn extracts_signed_integers_from_text() {
+        let line = "Sensor at x=2, y=-18: beacon at x=-2, y=15 - 3";
+        assert_eq!(parse_signed_ints::<i64>(line).unwrap(), [2, -18, -2, 15, 3]);
+        let e = parse_signed_ints::<u8>("x=300").unwrap_err();
+        assert_eq!((e.text.as_str(), e.offset), ("300", Some(2)));
+    }
+
+    #[test]
+    fn parses_a_fixed_number_of_values() {
+        assert_eq!(parse_n::<3, u64>("50 98 2").unwrap(), [50, 98, 2]);
+        let e = parse_n::<3, u64>("50 98").unwrap_err();
+        assert_eq!(e.message, "expected 3 numbers, found 2");
+    }
+}
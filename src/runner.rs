@@ -0,0 +1,346 @@
+use advent23::error::{Error, ParseError, Result};
+use advent23::solution::{Answer, Day, Part};
+use advent23::years::{self, DEFAULT_YEAR};
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::config;
+use crate::output::{print_json, Format, Record};
+
+/// Directory holding a day's inputs and answers.
+///
+/// That's `inputs/YYYY/dayN` under the configured inputs root, except that for the default year a day still laid
+/// out the old way, as `inputs/dayN`, is used where it is.
+pub fn input_dir(year: u16, day: u8) -> PathBuf {
+    let root = &config::settings().inputs;
+    let dir = root.join(year.to_string()).join(format!("day{}", day));
+    let legacy = root.join(format!("day{}", day));
+    if year == DEFAULT_YEAR && !dir.exists() && legacy.exists() {
+        return legacy;
+    }
+    dir
+}
+
+/// Default puzzle input for a day
+pub fn input_path(year: u16, day: u8) -> String {
+    input_dir(year, day).join("input.txt").display().to_string()
+}
+
+pub fn read_input(path: &str) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| Error::io(path, e))
+}
+
+/// Where a single run reads its puzzle input from
+#[derive(clap::Args, Debug)]
+pub struct InputArgs {
+    /// Puzzle input, or `-` for stdin [default: ./inputs/YYYY/dayN/input.txt]
+    file: Option<String>,
+
+    /// Puzzle input given inline instead of from a file
+    #[clap(long, conflicts_with = "file")]
+    input_text: Option<String>,
+}
+
+impl InputArgs {
+    /// The file `load` reads from, if there is one to watch for changes
+    pub fn watchable(&self, year: u16, day: u8) -> Option<String> {
+        match (&self.input_text, self.file.as_deref()) {
+            (Some(_), _) | (None, Some("-")) => None,
+            (None, Some(path)) => Some(path.to_string()),
+            (None, None) => Some(input_path(year, day)),
+        }
+    }
+
+    /// Read the input for `day`, returning a name to report it by and its contents
+    pub fn load(&self, year: u16, day: u8) -> Result<(String, String)> {
+        if let Some(text) = &self.input_text {
+            return Ok((String::from("<input-text>"), text.clone()));
+        }
+        match self.file.as_deref() {
+            Some("-") => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::io("<stdin>", e))?;
+                Ok((String::from("<stdin>"), input))
+            }
+            Some(path) => Ok((path.to_string(), read_input(path)?)),
+            None => {
+                let path = input_path(year, day);
+                let input = read_input(&path)?;
+                Ok((path, input))
+            }
+        }
+    }
+}
+
+/// Deserialize a TOML file, reporting errors with their line and column
+pub fn parse_toml<T: DeserializeOwned>(path: &str, contents: &str) -> Result<T> {
+    toml::from_str(contents).map_err(|e| {
+        let mut error = ParseError::new(e.message(), "").in_file(path);
+        if let Some(span) = e.span() {
+            let before = &contents[..span.start];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            error = error
+                .on_line(before.matches('\n').count() + 1)
+                .at_column(before[line_start..].chars().count() + 1);
+            error.text = contents[span].to_string();
+        }
+        Error::Parse(error)
+    })
+}
+
+/// Human-friendly duration with a unit suited to its magnitude
+pub fn format_duration(d: Duration) -> String {
+    let micros = d.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+/// Solve one part, returning the answer and how long it took
+pub fn timed(solution: &dyn Day, input: &str, part: Part) -> (Result<Answer>, Duration) {
+    let start = Instant::now();
+    let answer = solution.solve(input, part);
+    (answer, start.elapsed())
+}
+
+/// Each part that was solved, with its answer and how long solving it took
+pub type PartResults = Vec<(Part, Result<Answer>, Duration)>;
+
+/// Parse `input` once and solve both parts from it, timing each part's solve.
+///
+/// Fails only if the input doesn't parse; each part can still fail on its own.
+pub fn timed_both(
+    solution: &dyn Day,
+    input: &str,
+) -> Result<[(Part, Result<Answer>, Duration); 2]> {
+    let parsed = solution.parse(input)?;
+    Ok([Part::One, Part::Two].map(|part| {
+        let start = Instant::now();
+        let answer = solution.solve_parsed(&parsed, part);
+        (part, answer, start.elapsed())
+    }))
+}
+
+enum Outcome {
+    Solved([(Result<Answer>, Duration); 2]),
+    /// No input to run on, or neither part written yet
+    Skipped(Error),
+}
+
+/// A part still returning the scaffold's placeholder error, which isn't a failure
+fn unimplemented(answer: &Result<Answer>) -> bool {
+    matches!(answer, Err(Error::Unimplemented(_)))
+}
+
+struct Row {
+    day: u8,
+    title: &'static str,
+    input: String,
+    outcome: Outcome,
+}
+
+/// Solve both parts of every day in `days` on `jobs` worker threads.
+///
+/// Rows come back in the order of `days`, however the work was scheduled.
+fn run_days(year: u16, days: &[&'static dyn Day], jobs: usize) -> Vec<Row> {
+    let inputs: Vec<(String, Result<String>)> = days
+        .iter()
+        .map(|d| {
+            let path = input_path(year, d.day());
+            let input = read_input(&path);
+            (path, input)
+        })
+        .collect();
+    let tasks: Vec<(usize, Part)> = inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, input))| input.is_ok())
+        .flat_map(|(i, _)| [(i, Part::One), (i, Part::Two)])
+        .collect();
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, (Result<Answer>, Duration))> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, tasks.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = vec![];
+                    loop {
+                        let task = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(&(i, part)) = tasks.get(task) else {
+                            return done;
+                        };
+                        let Ok(input) = &inputs[i].1 else {
+                            unreachable!("only days with input are scheduled");
+                        };
+                        done.push((task, timed(days[i], input, part)));
+                    }
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().expect("solver panicked"))
+            .collect()
+    });
+    results.sort_by_key(|(task, _)| *task);
+
+    let mut results = results.into_iter().map(|(_, result)| result);
+    days.iter()
+        .zip(inputs)
+        .map(|(solution, (path, input))| Row {
+            day: solution.day(),
+            title: solution.title(),
+            input: path,
+            outcome: match input {
+                Ok(_) => match [results.next().unwrap(), results.next().unwrap()] {
+                    [(Err(e @ Error::Unimplemented(_)), _), (two, _)] if unimplemented(&two) => {
+                        Outcome::Skipped(e)
+                    }
+                    parts => Outcome::Solved(parts),
+                },
+                Err(e) => Outcome::Skipped(e),
+            },
+        })
+        .collect()
+}
+
+/// Whether every part that ran found an answer
+fn all_solved(rows: &[Row]) -> bool {
+    rows.iter().all(|row| match &row.outcome {
+        Outcome::Solved(parts) => parts
+            .iter()
+            .all(|(answer, _)| answer.is_ok() || unimplemented(answer)),
+        Outcome::Skipped(_) => true,
+    })
+}
+
+fn print_records(rows: Vec<Row>) {
+    for row in rows {
+        let path = row.input;
+        match row.outcome {
+            Outcome::Solved(parts) => {
+                for (part, (answer, time)) in [Part::One, Part::Two].into_iter().zip(parts) {
+                    let answer = answer.map_err(|e| e.in_file(&path));
+                    print_json(&Record::new(row.day, part, &path, &answer, time));
+                }
+            }
+            Outcome::Skipped(e) => {
+                let skipped = Err(e);
+                for part in [Part::One, Part::Two] {
+                    print_json(&Record::new(row.day, part, &path, &skipped, Duration::ZERO));
+                }
+            }
+        }
+    }
+}
+
+/// Which days to run together, and how
+#[derive(clap::Args, Debug)]
+pub struct RunAllArgs {
+    /// Days to run [default: every implemented day]
+    days: Vec<u8>,
+
+    /// Number of worker threads [default: one per core]
+    #[clap(long, short, value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+}
+
+/// Run both parts of the chosen days against their `input.txt`, in parallel.
+///
+/// Returns whether every part that ran found an answer.
+pub fn run_all(args: &RunAllArgs, year: u16, format: Format) -> Result<bool> {
+    let days: Vec<&'static dyn Day> = match &args.days[..] {
+        [] => years::days(year).to_vec(),
+        days => days
+            .iter()
+            .map(|&day| advent23::day_in(year, day))
+            .collect::<Result<_>>()?,
+    };
+    let jobs = args.jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, |n| n.get()),
+        |n| n as usize,
+    );
+    // Collect everything before printing so results come out in day order
+    let start = Instant::now();
+    let rows = run_days(year, &days, jobs);
+    let wall = start.elapsed();
+    let serial: Duration = rows
+        .iter()
+        .filter_map(|row| match &row.outcome {
+            Outcome::Solved([(_, one), (_, two)]) => Some(*one + *two),
+            Outcome::Skipped(_) => None,
+        })
+        .sum();
+    let solved = all_solved(&rows);
+    if format == Format::Json {
+        print_records(rows);
+        print_json(&serde_json::json!({
+            "serial_ns": serial.as_nanos() as u64,
+            "wall_ns": wall.as_nanos() as u64,
+            "jobs": jobs,
+        }));
+        return Ok(solved);
+    }
+
+    println!(
+        "{:>3}  {:<32} {:>16} {:>10} {:>16} {:>10}",
+        "day", "title", "part 1", "time", "part 2", "time"
+    );
+    let mut errors: Vec<String> = vec![];
+    for row in rows {
+        match row.outcome {
+            Outcome::Solved([(one, one_time), (two, two_time)]) => {
+                let mut cell = |part: Part, answer: Result<Answer>| match answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(Error::Unimplemented(_)) => String::from("-"),
+                    Err(e) => {
+                        errors.push(format!(
+                            "day {} part {}: {}",
+                            row.day,
+                            part,
+                            e.in_file(&row.input)
+                        ));
+                        String::from("error")
+                    }
+                };
+                println!(
+                    "{:>3}  {:<32} {:>16} {:>10} {:>16} {:>10}",
+                    row.day,
+                    row.title,
+                    cell(Part::One, one),
+                    format_duration(one_time),
+                    cell(Part::Two, two),
+                    format_duration(two_time)
+                );
+            }
+            Outcome::Skipped(e) => {
+                println!("{:>3}  {:<32} skipped, {}", row.day, row.title, e);
+            }
+        }
+    }
+    println!("{:>93}", format!("total {}", format_duration(serial)));
+    println!(
+        "{:>93}",
+        format!(
+            "wall {} on {} thread{}",
+            format_duration(wall),
+            jobs,
+            if jobs == 1 { "" } else { "s" }
+        )
+    );
+    for error in errors {
+        eprintln!("error: {}", error);
+    }
+    Ok(solved)
+}
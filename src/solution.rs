@@ -0,0 +1,156 @@
+use crate::error::Result;
+use std::any::Any;
+use std::fmt;
+
+/// Which half of a day's puzzle to solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Unsigned(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Signed(n)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A single day's puzzle.
+///
+/// `parse` turns the raw puzzle input into the day's model once, and both parts
+/// are evaluated against that model.
+pub trait Solution {
+    /// Day of the month, starting at 1
+    const DAY: u8;
+    /// Puzzle title as it appears on the puzzle page
+    const TITLE: &'static str;
+
+    /// Parsed representation of the puzzle input shared by both parts
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    /// Human-readable dump of the parsed input, for days that have one
+    fn describe(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// A day's parsed input with its concrete type erased
+pub type Parsed = Box<dyn Any>;
+
+/// Object-safe view of a `Solution` so days can be stored in a registry.
+pub trait Day: Sync {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    fn solve(&self, input: &str, part: Part) -> Result<Answer>;
+    fn describe(&self, input: &str) -> Result<Option<String>>;
+
+    /// Parse without solving, so parsing and solving can be timed separately
+    fn parse(&self, input: &str) -> Result<Parsed>;
+    /// Solve a part against the result of `parse`
+    ///
+    /// Panics if `parsed` came from a different day.
+    fn solve_parsed(&self, parsed: &Parsed, part: Part) -> Result<Answer>;
+
+    fn part1(&self, input: &str) -> Result<Answer> {
+        self.solve(input, Part::One)
+    }
+
+    fn part2(&self, input: &str) -> Result<Answer> {
+        self.solve(input, Part::Two)
+    }
+}
+
+impl<S> Day for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn solve(&self, input: &str, part: Part) -> Result<Answer> {
+        let parsed = S::parse(input)?;
+        match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        }
+    }
+
+    fn describe(&self, input: &str) -> Result<Option<String>> {
+        Ok(S::describe(&S::parse(input)?))
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_parsed(&self, parsed: &Parsed, part: Part) -> Result<Answer> {
+        let parsed = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input belongs to a different day");
+        match part {
+            Part::One => S::part1(parsed),
+            Part::Two => S::part2(parsed),
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use advent23::error::Result;
+use advent23::solution::{Day, Part};
+use serde::Serialize;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::output::{part_number, print_json, Format};
+use crate::runner::{format_duration, PartResults};
+
+/// The system allocator, keeping count of what passes through it once `--stats`
+/// has first called [`measure`]; until then it only forwards to [`System`], so
+/// other commands don't pay for the counters
+struct Counting;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes held, relative to when counting started; frees of memory allocated
+/// before then can take it below zero
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+fn grew(bytes: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(bytes as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(bytes as i64, Ordering::Relaxed) + bytes as i64;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn shrank(bytes: usize) {
+    LIVE.fetch_sub(bytes as i64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if counting() && !ptr.is_null() {
+            grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if counting() && !ptr.is_null() {
+            grew(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if counting() {
+            shrank(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if counting() && !new.is_null() {
+            shrank(layout.size());
+            grew(new_size);
+        }
+        new
+    }
+}
+
+/// What one phase of a run cost
+#[derive(Debug, Serialize)]
+pub struct Phase {
+    pub phase: String,
+    pub time_ns: u64,
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Most memory held at once during the phase, beyond what was held going in
+    pub peak_bytes: u64,
+}
+
+/// Run `f`, counting the time it takes and the allocations it makes
+fn measure<T>(phase: &str, f: impl FnOnce() -> T) -> (T, Phase) {
+    COUNTING.store(true, Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = f();
+    let time = start.elapsed();
+    let phase = Phase {
+        phase: phase.to_string(),
+        time_ns: time.as_nanos() as u64,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_bytes: (PEAK.load(Ordering::Relaxed) - live).max(0) as u64,
+    };
+    (result, phase)
+}
+
+/// Parse once, then solve each of `parts`, measuring every phase on its own
+pub fn run_measured(
+    solution: &dyn Day,
+    input: &str,
+    parts: &[Part],
+) -> Result<(PartResults, Vec<Phase>)> {
+    let (parsed, parse) = measure("parse", || solution.parse(input));
+    let parsed = parsed?;
+    let mut phases = vec![parse];
+    let mut results = vec![];
+    for &part in parts {
+        let name = format!("part{}", part_number(part));
+        let (answer, phase) = measure(&name, || solution.solve_parsed(&parsed, part));
+        results.push((part, answer, Duration::from_nanos(phase.time_ns)));
+        phases.push(phase);
+    }
+    Ok((results, phases))
+}
+
+fn bytes(n: u64) -> String {
+    match n {
+        0..=1023 => format!("{} B", n),
+        1024..=1048575 => format!("{:.1} KiB", n as f64 / 1024.0),
+        _ => format!("{:.1} MiB", n as f64 / 1048576.0),
+    }
+}
+
+/// Show a run's phases: as a table on stderr, or as one more JSON record
+pub fn print_phases(day: u8, phases: &[Phase], format: Format) {
+    if format == Format::Json {
+        return print_json(&serde_json::json!({ "day": day, "stats": phases }));
+    }
+    eprintln!(
+        "{:<8} {:>10} {:>10} {:>12} {:>12}",
+        "phase", "time", "allocs", "allocated", "peak"
+    );
+    for phase in phases {
+        eprintln!(
+            "{:<8} {:>10} {:>10} {:>12} {:>12}",
+            phase.phase,
+            format_duration(Duration::from_nanos(phase.time_ns)),
+            phase.allocations,
+            bytes(phase.allocated_bytes),
+            bytes(phase.peak_bytes)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_allocations_in_a_phase() {
+        let (v, phase) = measure("test", || vec![0u8; 4096]);
+        assert!(phase.allocations >= 1);
+        assert!(phase.allocated_bytes >= 4096);
+        drop(v);
+    }
+}
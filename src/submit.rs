@@ -0,0 +1,339 @@
+use advent23::error::{Error, Result};
+use advent23::solution::Part;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::fetch::{wait_for_turn, ServerArgs};
+use crate::output::{part_number, print_json, Format};
+use crate::runner::{input_dir, parse_toml, InputArgs};
+
+#[derive(clap::Args, Debug)]
+pub struct SubmitArgs {
+    /// Day to solve and submit
+    #[clap(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part to solve and submit
+    #[clap(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+/// What the server made of an answer that it actually judged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+}
+
+/// The server's reply to a submission
+#[derive(Debug, PartialEq, Eq)]
+pub enum Response {
+    Judged(Verdict),
+    /// Submitted too soon after the last wrong answer
+    Wait(u64),
+    /// This part has already been solved, or isn't unlocked yet
+    WrongLevel,
+    Unrecognised(String),
+}
+
+impl Response {
+    /// Make sense of the page the server sends back after posting an answer
+    pub fn from_page(page: &str) -> Self {
+        let text = match page.split_once("<article>") {
+            Some((_, rest)) => rest.split_once("</article>").map_or(rest, |(a, _)| a),
+            None => page,
+        };
+        if text.contains("That's the right answer") {
+            Response::Judged(Verdict::Correct)
+        } else if text.contains("That's not the right answer") {
+            Response::Judged(if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if text.contains("You gave an answer too recently") {
+            let wait = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait")
+                .unwrap()
+                .captures(text)
+                .map_or(0, |c| {
+                    let minutes: u64 = c.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+                    minutes * 60 + c[2].parse::<u64>().unwrap()
+                });
+            Response::Wait(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            let plain = Regex::new(r"<[^>]*>").unwrap().replace_all(text, "");
+            Response::Unrecognised(plain.trim().to_string())
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every judged submission for one day, from `inputs/YYYY/dayN/submissions.toml`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part1: Vec<Attempt>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part2: Vec<Attempt>,
+}
+
+impl History {
+    fn path(year: u16, day: u8) -> PathBuf {
+        input_dir(year, day).join("submissions.toml")
+    }
+
+    pub fn load(year: u16, day: u8) -> Result<Self> {
+        let path = Self::path(year, day).display().to_string();
+        match fs::read_to_string(&path) {
+            Ok(contents) => parse_toml(&path, &contents),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, year: u16, day: u8) -> Result<()> {
+        let path = Self::path(year, day);
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, toml::to_string(self).unwrap()))
+            .map_err(|e| Error::io(&path.display().to_string(), e))
+    }
+
+    pub fn attempts(&mut self, part: Part) -> &mut Vec<Attempt> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+
+    /// Why `answer` shouldn't be sent, if earlier verdicts already settle it
+    pub fn objection(&self, part: Part, answer: &str) -> Option<String> {
+        let attempts = match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        };
+        let number: Option<i128> = answer.parse().ok();
+        attempts.iter().find_map(|attempt| {
+            if attempt.answer == answer {
+                return Some(match attempt.verdict {
+                    Verdict::Correct => format!("{} was already accepted", answer),
+                    _ => format!("{} was already rejected", answer),
+                });
+            }
+            let bound: i128 = attempt.answer.parse().ok()?;
+            match attempt.verdict {
+                Verdict::TooHigh if number? >= bound => {
+                    Some(format!("{} was too high, so {} is too", bound, answer))
+                }
+                Verdict::TooLow if number? <= bound => {
+                    Some(format!("{} was too low, so {} is too", bound, answer))
+                }
+                Verdict::Correct => Some(format!("the answer is already known to be {}", bound)),
+                _ => None,
+            }
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Judged(Verdict),
+    Wait,
+    WrongLevel,
+    Refused,
+    Unrecognised,
+}
+
+/// A submission as emitted by `--format json`
+#[derive(Debug, Serialize)]
+struct SubmitRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    #[serde(serialize_with = "status_name")]
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_secs: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+/// `too_high` rather than `{"judged":"too_high"}`
+fn status_name<S: serde::Serializer>(status: &Status, serializer: S) -> Result<S::Ok, S::Error> {
+    match status {
+        Status::Judged(verdict) => verdict.serialize(serializer),
+        status => status.serialize(serializer),
+    }
+}
+
+/// Solve a day and submit the answer.
+///
+/// Returns `true` if the server accepted it.
+pub fn run_submit(args: &SubmitArgs, year: u16, format: Format) -> Result<bool> {
+    let part = if args.part == 1 { Part::One } else { Part::Two };
+    let solution = advent23::day_in(year, args.day)?;
+    let (file, input) = args.input.load(year, args.day)?;
+    let answer = solution
+        .solve(&input, part)
+        .map_err(|e| e.in_file(&file))?
+        .to_string();
+
+    let mut history = History::load(year, args.day)?;
+    let (status, wait_secs, message) = match history.objection(part, &answer) {
+        Some(objection) => (Status::Refused, None, Some(objection)),
+        None => {
+            let client = args.server.client()?;
+            wait_for_turn()?;
+            let page = client.submit(year, args.day, part, &answer)?;
+            match Response::from_page(&page) {
+                Response::Judged(verdict) => {
+                    history.attempts(part).push(Attempt {
+                        answer: answer.clone(),
+                        verdict,
+                    });
+                    history.save(year, args.day)?;
+                    (Status::Judged(verdict), None, None)
+                }
+                Response::Wait(secs) => (Status::Wait, Some(secs), None),
+                Response::WrongLevel => (Status::WrongLevel, None, None),
+                Response::Unrecognised(text) => (Status::Unrecognised, None, Some(text)),
+            }
+        }
+    };
+
+    match format {
+        Format::Json => print_json(&SubmitRecord {
+            day: args.day,
+            part: part_number(part),
+            answer: &answer,
+            status,
+            wait_secs,
+            message,
+        }),
+        Format::Plain => {
+            let outcome = match status {
+                Status::Judged(Verdict::Correct) => String::from("correct!"),
+                Status::Judged(Verdict::Wrong) => String::from("wrong"),
+                Status::Judged(Verdict::TooHigh) => String::from("wrong, too high"),
+                Status::Judged(Verdict::TooLow) => String::from("wrong, too low"),
+                Status::Wait => format!(
+                    "answered too recently, wait {}s",
+                    wait_secs.unwrap_or_default()
+                ),
+                Status::WrongLevel => String::from("part already solved or still locked"),
+                Status::Refused => format!("not submitted: {}", message.unwrap_or_default()),
+                Status::Unrecognised => {
+                    format!("unrecognised response: {}", message.unwrap_or_default())
+                }
+            };
+            println!("day {} part {}: {} -> {}", args.day, part, answer, outcome);
+        }
+    }
+    Ok(status == Status::Judged(Verdict::Correct))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::stub_server;
+    use crate::fetch::Client;
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            answer: answer.to_string(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn reads_verdicts_from_response_pages() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Response::from_page(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Response::Judged(Verdict::Correct)
+        );
+        assert_eq!(
+            Response::from_page(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Response::Judged(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Response::from_page(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Response::Judged(Verdict::TooLow)
+        );
+        assert_eq!(
+            Response::from_page(&page("That's not the right answer.")),
+            Response::Judged(Verdict::Wrong)
+        );
+        assert_eq!(
+            Response::from_page(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Response::Wait(65)
+        );
+        assert_eq!(
+            Response::from_page(&page("You don't seem to be solving the right level.")),
+            Response::WrongLevel
+        );
+    }
+
+    #[test]
+    fn history_refuses_settled_answers() {
+        let history = History {
+            part1: vec![
+                attempt("500", Verdict::TooHigh),
+                attempt("100", Verdict::TooLow),
+                attempt("250", Verdict::Wrong),
+            ],
+            part2: vec![attempt("42", Verdict::Correct)],
+        };
+        assert!(history.objection(Part::One, "250").is_some());
+        assert!(history.objection(Part::One, "600").is_some());
+        assert!(history.objection(Part::One, "100").is_some());
+        assert!(history.objection(Part::One, "99").is_some());
+        assert_eq!(history.objection(Part::One, "300"), None);
+        assert!(history.objection(Part::Two, "43").is_some());
+    }
+
+    #[test]
+    fn posts_level_and_answer() {
+        let (url, server) = stub_server(
+            "200 OK",
+            "<article><p>That's the right answer!</p></article>",
+        );
+        let page = Client::new(&url, "secret")
+            .submit(2023, 6, Part::Two, "71503")
+            .unwrap();
+        assert_eq!(
+            Response::from_page(&page),
+            Response::Judged(Verdict::Correct)
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/6/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=71503"));
+    }
+}
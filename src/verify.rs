@@ -0,0 +1,203 @@
+use advent23::error::{Error, Result};
+use advent23::log::paint;
+use advent23::solution::{Day, Part};
+use advent23::years;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+
+use crate::output::{part_number, print_json, Format};
+use crate::runner::{input_dir, parse_toml, read_input};
+
+/// A recorded answer; puzzle answers are almost always numbers, but not always
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Number(n) => write!(f, "{}", n),
+            Expected::Signed(n) => write!(f, "{}", n),
+            Expected::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Known-correct answers for a single input file
+#[derive(Debug, Default, Deserialize)]
+pub struct FileAnswers {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+impl FileAnswers {
+    pub fn get(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Contents of `inputs/YYYY/dayN/answers.toml`, keyed by input file stem
+/// (`input` for `input.txt`, `sample2` for `sample2.txt`, ...)
+pub type Answers = BTreeMap<String, FileAnswers>;
+
+/// Load a day's recorded answers, or `None` if it has no answers file
+pub fn load_answers(year: u16, day: u8) -> Option<Result<Answers>> {
+    let path = input_dir(year, day)
+        .join("answers.toml")
+        .display()
+        .to_string();
+    let contents = fs::read_to_string(&path).ok()?;
+    Some(parse_toml(&path, &contents))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Ok,
+    Fail,
+    Error,
+}
+
+/// One comparison against a recorded answer, or a failure to make one
+#[derive(Debug, Serialize)]
+struct Check {
+    day: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part: Option<u8>,
+    input: String,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    diagnostics: Vec<String>,
+}
+
+impl Check {
+    fn error(day: u8, part: Option<Part>, input: &str, e: Error) -> Self {
+        Self {
+            day,
+            part: part.map(part_number),
+            input: input.to_string(),
+            status: Status::Error,
+            expected: None,
+            answer: None,
+            diagnostics: vec![e.in_file(input).to_string()],
+        }
+    }
+
+    fn print_plain(&self) {
+        let part = match self.part {
+            Some(part) => format!("part {} ", part),
+            None => String::new(),
+        };
+        match self.status {
+            Status::Ok => println!(
+                "day {:>2}  {}  {}{}",
+                self.day,
+                paint("32", "ok  "),
+                part,
+                self.input
+            ),
+            Status::Fail => println!(
+                "day {:>2}  {}  {}{}: expected {}, got {}",
+                self.day,
+                paint("31", "FAIL"),
+                part,
+                self.input,
+                self.expected.as_deref().unwrap_or_default(),
+                self.answer.as_deref().unwrap_or_default()
+            ),
+            Status::Error => println!(
+                "day {:>2}  {}  {}{}",
+                self.day,
+                paint("31", "FAIL"),
+                part,
+                self.diagnostics.join("; ")
+            ),
+        }
+    }
+}
+
+fn check_day(year: u16, solution: &dyn Day, checks: &mut Vec<Check>) {
+    let day = solution.day();
+    let answers = match load_answers(year, day) {
+        Some(Ok(answers)) => answers,
+        Some(Err(e)) => {
+            let path = input_dir(year, day).join("answers.toml");
+            return checks.push(Check::error(day, None, &path.display().to_string(), e));
+        }
+        None => return,
+    };
+    for (stem, expected) in answers.iter() {
+        let path = input_dir(year, day)
+            .join(format!("{}.txt", stem))
+            .display()
+            .to_string();
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                checks.push(Check::error(day, None, &path, e));
+                continue;
+            }
+        };
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = expected.get(part) else {
+                continue;
+            };
+            let check = match solution.solve(&input, part) {
+                Ok(answer) => {
+                    let answer = answer.to_string();
+                    let expected = expected.to_string();
+                    Check {
+                        day,
+                        part: Some(part_number(part)),
+                        input: path.clone(),
+                        status: if answer == expected {
+                            Status::Ok
+                        } else {
+                            Status::Fail
+                        },
+                        expected: Some(expected),
+                        answer: Some(answer),
+                        diagnostics: vec![],
+                    }
+                }
+                Err(e) => Check::error(day, Some(part), &path, e),
+            };
+            checks.push(check);
+        }
+    }
+}
+
+/// Run every day against every input that has a recorded answer.
+///
+/// Returns `true` if every recorded answer was reproduced.
+pub fn run_verify(year: u16, format: Format) -> bool {
+    let mut checks: Vec<Check> = vec![];
+    for solution in years::days(year) {
+        check_day(year, *solution, &mut checks);
+    }
+    let passed = checks.iter().filter(|c| c.status == Status::Ok).count();
+    let failed = checks.len() - passed;
+    for check in checks.iter() {
+        match format {
+            Format::Plain => check.print_plain(),
+            Format::Json => print_json(check),
+        }
+    }
+    if format == Format::Plain {
+        println!("\n{} passed, {} failed", passed, failed);
+    }
+    failed == 0
+}
@@ -0,0 +1,100 @@
+use advent23::error::Result;
+use advent23::solution::Part;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::output::{print_json, Format, Record};
+use crate::runner::{format_duration, PartResults};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// The input a run was given, and what each part made of it
+pub type Run = (String, PartResults);
+
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|p| fs::metadata(p).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// How an answer compares to the one from the previous run
+fn change(previous: Option<&String>, answer: &str) -> String {
+    match previous {
+        None => String::new(),
+        Some(previous) if previous == answer => String::from("unchanged"),
+        Some(previous) => match (previous.parse::<i128>(), answer.parse::<i128>()) {
+            (Ok(before), Ok(after)) => format!("was {} ({:+})", previous, after - before),
+            _ => format!("was {}", previous),
+        },
+    }
+}
+
+/// Call `run` now and again whenever one of `paths` is modified, until interrupted
+pub fn watch(
+    day: u8,
+    paths: &[PathBuf],
+    format: Format,
+    mut run: impl FnMut() -> Result<Run>,
+) -> ! {
+    let mut previous: HashMap<Part, String> = HashMap::new();
+    let mut count = 0;
+    loop {
+        let seen = modified(paths);
+        count += 1;
+        if format == Format::Plain {
+            print!("\x1b[2J\x1b[H");
+            println!(
+                "day {}, run {}, watching {} file(s); Ctrl-C to stop\n",
+                day,
+                count,
+                paths.len()
+            );
+        }
+        match run() {
+            Ok((file, results)) => {
+                for (part, answer, time) in results {
+                    let answer = answer.map_err(|e| e.in_file(&file));
+                    match (format, &answer) {
+                        (Format::Json, _) => {
+                            print_json(&Record::new(day, part, &file, &answer, time))
+                        }
+                        (Format::Plain, Ok(answer)) => {
+                            let answer = answer.to_string();
+                            println!(
+                                "part {}  {:>16} {:>10}  {}",
+                                part,
+                                answer,
+                                format_duration(time),
+                                change(previous.get(&part), &answer)
+                            );
+                            previous.insert(part, answer);
+                        }
+                        (Format::Plain, Err(e)) => println!("part {}  error: {}", part, e),
+                    }
+                }
+            }
+            Err(e) => eprintln!("error: {}", e),
+        }
+        while modified(paths) == seen {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_changes_between_runs() {
+        let previous = String::from("288");
+        assert_eq!(change(None, "288"), "");
+        assert_eq!(change(Some(&previous), "288"), "unchanged");
+        assert_eq!(change(Some(&previous), "280"), "was 288 (-8)");
+        assert_eq!(change(Some(&previous), "abc"), "was 288");
+    }
+}
@@ -0,0 +1,50 @@
+use crate::solution::Day;
+
+pub mod y2023;
+
+/// Highest day number the CLI accepts
+pub const LAST_DAY: u8 = 31;
+
+/// Year used when none is given, and the one `inputs/dayN` belongs to
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// One year's worth of solutions
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [&'static dyn Day],
+}
+
+/// Every year with at least one implemented day, in order
+pub static YEARS: &[Year] = &[Year {
+    year: 2023,
+    days: y2023::DAYS,
+}];
+
+/// The days implemented for `year`, empty if there are none
+pub fn days(year: u16) -> &'static [&'static dyn Day] {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .map_or(&[], |y| y.days)
+}
+
+/// Look up a day's solution, or `None` if it has not been implemented yet
+pub fn get(year: u16, day: u8) -> Option<&'static dyn Day> {
+    days(year).iter().find(|d| d.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Part;
+
+    fn check(year: u16, day: u8, input: &str, part: Part, expected: &str) {
+        let solution = super::get(year, day).expect("day is not registered");
+        let answer = solution
+            .solve(input, part)
+            .unwrap_or_else(|e| panic!("{}", e));
+        assert_eq!(answer.to_string(), expected);
+    }
+
+    // One test per sample file and part with a recorded answer, see build.rs
+    include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+}
@@ -0,0 +1,85 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+use regex::Regex;
+
+/// sum first and last digit of each line
+pub struct Day01;
+
+fn parse_digit(input: &str) -> Option<u64> {
+    match input {
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        _ => input.parse::<u64>().ok(),
+    }
+}
+
+fn no_digits(i: usize) -> Error {
+    Error::unsolvable(format!("No digits found on line {}", i + 1))
+}
+
+fn extract_digits_written(input: &str) -> Result<u64> {
+    let first_re = Regex::new(r"(one|two|three|four|five|six|seven|eight|nine|\d)").unwrap();
+    let last_re = Regex::new(r".*(one|two|three|four|five|six|seven|eight|nine|\d).*$").unwrap();
+
+    let mut count: u64 = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        let first_digit = first_re
+            .captures(line)
+            .and_then(|cap| parse_digit(cap.get(1)?.as_str()))
+            .ok_or_else(|| no_digits(i))?;
+        let last_digit = last_re
+            .captures(line)
+            .and_then(|cap| parse_digit(cap.get(1)?.as_str()))
+            .ok_or_else(|| no_digits(i))?;
+        count += (first_digit * 10) + last_digit
+    }
+
+    Ok(count)
+}
+
+fn extract_digits(input: &str) -> Result<u64> {
+    let re = Regex::new(r"\d").unwrap();
+    let mut count: u64 = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        let c: Vec<_> = re.find_iter(line).collect();
+        let first_digit = c
+            .first()
+            .and_then(|m| parse_digit(m.as_str()))
+            .ok_or_else(|| no_digits(i))?;
+        let last_digit = c
+            .last()
+            .and_then(|m| parse_digit(m.as_str()))
+            .ok_or_else(|| no_digits(i))?;
+        count += (first_digit * 10) + last_digit
+    }
+
+    Ok(count)
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer> {
+        Ok(extract_digits(input)?.into())
+    }
+
+    fn part2(input: &String) -> Result<Answer> {
+        Ok(extract_digits_written(input)?.into())
+    }
+}
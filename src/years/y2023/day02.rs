@@ -0,0 +1,117 @@
+use crate::debug;
+use crate::error::{ParseError, Result};
+use crate::log::{self, Level};
+use crate::solution::{Answer, Solution};
+
+/// multidimensonal data processing
+pub struct Day02;
+
+pub struct Game {
+    id: u64,
+    red: Vec<u64>,
+    blue: Vec<u64>,
+    green: Vec<u64>,
+}
+
+impl Game {
+    pub fn new(id: u64, red: Vec<u64>, blue: Vec<u64>, green: Vec<u64>) -> Self {
+        Self {
+            id,
+            red,
+            blue,
+            green,
+        }
+    }
+
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        // Parses "Game 1: 1 blue, 1 red; 10 red; 8 red, 1 blue, 1 green; 1 green, 5 blue"
+        let Some((game, rolls)) = input.split_once(": ") else {
+            return Err(ParseError::new("expected \"Game <id>: <rolls>\"", input));
+        };
+        let id_text = game.trim().trim_start_matches("Game ");
+        let id: u64 = id_text
+            .parse()
+            .map_err(|_| ParseError::in_line("invalid game id", input, id_text))?;
+        let rolls: Vec<&str> = rolls.split(';').collect();
+        let mut reds: Vec<u64> = vec![];
+        let mut blues: Vec<u64> = vec![];
+        let mut greens: Vec<u64> = vec![];
+        for roll in rolls {
+            let count_and_colors: Vec<&str> = roll.split(", ").collect();
+            for count_and_color in count_and_colors {
+                let count_and_color = count_and_color.trim();
+                let Some((count, color)) = count_and_color.split_once(' ') else {
+                    return Err(ParseError::in_line(
+                        "expected \"<count> <color>\"",
+                        input,
+                        count_and_color,
+                    ));
+                };
+                let count: u64 = count
+                    .trim()
+                    .parse()
+                    .map_err(|_| ParseError::in_line("invalid count", input, count))?;
+                match color {
+                    "red" => reds.push(count),
+                    "blue" => blues.push(count),
+                    "green" => greens.push(count),
+                    _ => return Err(ParseError::in_line("unknown color", input, color)),
+                }
+            }
+        }
+        Ok(Self::new(id, reds, blues, greens))
+    }
+
+    pub fn max_red(&self) -> u64 {
+        self.red.iter().cloned().max().unwrap_or(0)
+    }
+
+    pub fn max_blue(&self) -> u64 {
+        self.blue.iter().cloned().max().unwrap_or(0)
+    }
+
+    pub fn max_green(&self) -> u64 {
+        self.green.iter().cloned().max().unwrap_or(0)
+    }
+
+    pub fn multiply_colors(&self) -> u64 {
+        self.max_red() * self.max_green() * self.max_blue()
+    }
+
+    pub fn is_possible(&self, red: u64, green: u64, blue: u64) -> bool {
+        red >= self.max_red() && green >= self.max_green() && blue >= self.max_blue()
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>> {
+        let mut games: Vec<Game> = vec![];
+        for (i, line) in input.lines().enumerate() {
+            games.push(Game::from_string(line).map_err(|e| e.on_line(i + 1))?);
+        }
+        Ok(games)
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<Answer> {
+        let possible = games.iter().filter(|game| game.is_possible(12, 13, 14));
+        if log::enabled(Level::Debug) {
+            let ids: Vec<u64> = possible.clone().map(|game| game.id).collect();
+            debug!("possible games: {:?}", ids);
+        }
+        Ok(possible.map(|game| game.id).sum::<u64>().into())
+    }
+
+    /// What is the sum of the minimum number of each color multiplied together
+    fn part2(games: &Vec<Game>) -> Result<Answer> {
+        Ok(games
+            .iter()
+            .map(|game| game.multiply_colors())
+            .sum::<u64>()
+            .into())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::error::Result;
+use crate::grid::{Direction, Grid, Point};
+use crate::solution::{Answer, Solution};
+
+/// 2D array traversal
+pub struct Day03;
+
+fn is_special(c: char) -> bool {
+    for special in ['!', '@', '#', '$', '%', '^', '&', '*', '+', '-', '/', '='] {
+        if c == special {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_special_adjacent(world: &Grid<char>, p: Point) -> bool {
+    world.neighbors8(p).any(|adj| is_special(world[adj]))
+}
+
+fn part1(world: &Grid<char>) -> u32 {
+    let mut current_number = 0;
+    let mut sum = 0;
+    let mut is_valid = false;
+    for (p, c) in world.iter() {
+        if c.is_ascii_digit() {
+            current_number = (current_number * 10) + c.to_digit(10).unwrap();
+            is_valid = is_valid || is_special_adjacent(world, p);
+        } else {
+            if is_valid {
+                sum += current_number;
+                is_valid = false;
+            }
+            current_number = 0;
+        }
+    }
+    sum
+}
+
+fn digit_at(world: &Grid<char>, p: Point) -> Option<u32> {
+    world.get(p)?.to_digit(10)
+}
+
+/// The number with a digit at `p`, and where it starts
+fn gather_number(world: &Grid<char>, p: Point) -> Option<(u32, Point)> {
+    digit_at(world, p)?;
+    let mut start = p;
+    while let Some(next) = world.step(start, Direction::West) {
+        if digit_at(world, next).is_none() {
+            break;
+        }
+        start = next;
+    }
+    let mut number = 0;
+    let mut tile = Some(start);
+    while let Some(n) = tile.and_then(|t| digit_at(world, t)) {
+        number = (number * 10) + n;
+        tile = world.step(tile.unwrap(), Direction::East);
+    }
+    Some((number, start))
+}
+
+fn gear_ratio(world: &Grid<char>, p: Point) -> Option<u32> {
+    let mut numbers: Vec<(u32, Point)> = vec![];
+    for number in world
+        .neighbors8(p)
+        .filter_map(|adj| gather_number(world, adj))
+    {
+        if !numbers.contains(&number) {
+            numbers.push(number);
+        }
+    }
+    if numbers.len() == 2 {
+        Some(numbers[0].0 * numbers[1].0)
+    } else {
+        None
+    }
+}
+
+fn part2(world: &Grid<char>) -> u32 {
+    world
+        .find_all(|&c| c == '*')
+        .filter_map(|p| gear_ratio(world, p))
+        .sum()
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(world: &Grid<char>) -> Result<Answer> {
+        Ok(part1(world).into())
+    }
+
+    /// Find "gear ratios"
+    fn part2(world: &Grid<char>) -> Result<Answer> {
+        Ok(part2(world).into())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::error::{Error, ParseError, Result};
+use crate::parse_number_list;
+use crate::solution::{Answer, Solution};
+
+/// set comparison
+pub struct Day04;
+
+pub fn score_line(line: &str) -> Result<u64, ParseError> {
+    let Some((_, data)) = line.split_once(": ") else {
+        return Err(ParseError::new("expected \"Card <id>: <numbers>\"", line));
+    };
+    let Some((winners, assigned)) = data.split_once(" | ") else {
+        return Err(ParseError::in_line(
+            "expected \"<winners> | <numbers>\"",
+            line,
+            data,
+        ));
+    };
+    let winners: Vec<u64> = parse_number_list(winners).map_err(|e| e.within(line, winners))?;
+    let assigned: Vec<u64> = parse_number_list(assigned).map_err(|e| e.within(line, assigned))?;
+    let mut score: u64 = 0;
+    // I know this looks dumb, it's because linear search is often faster whem there are very few elements
+    for number in &assigned {
+        for winner in &winners {
+            if number == winner {
+                score += 1;
+            }
+        }
+    }
+    Ok(score)
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    /// Number of winning numbers on each card
+    type Input = Vec<u64>;
+
+    fn parse(contents: &str) -> Result<Vec<u64>> {
+        let mut scores = vec![];
+        for (i, line) in contents.lines().enumerate() {
+            scores.push(score_line(line).map_err(|e| e.on_line(i + 1))?);
+        }
+        Ok(scores)
+    }
+
+    fn part1(scores: &Vec<u64>) -> Result<Answer> {
+        let mut sum: u64 = 0;
+        for score in scores {
+            if *score > 0 {
+                sum += u64::pow(2, (score - 1).try_into().unwrap());
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(scores: &Vec<u64>) -> Result<Answer> {
+        let mut copies: Vec<u64> = vec![1; scores.len()];
+        for (i, matches) in scores.iter().enumerate() {
+            if i + *matches as usize >= copies.len() {
+                return Err(Error::unsolvable(format!(
+                    "card {} wins copies of cards past the end of the table",
+                    i + 1
+                )));
+            }
+            for j in 0..*matches as usize {
+                copies[i + j + 1] += copies[i];
+            }
+        }
+        Ok(copies.iter().sum::<u64>().into())
+    }
+}
@@ -0,0 +1,283 @@
+use crate::error::{Error, ParseError, Result};
+use crate::intervals::{Interval, IntervalSet, RangeMap};
+use crate::log::{self, Level};
+use crate::parse::{parse_n, parse_number_list};
+use crate::solution::{Answer, Solution};
+use crate::{debug, trace};
+use std::fmt::Write;
+
+pub struct Day05;
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone, Copy)]
+pub struct Correspondence {
+    source_start: u64,
+    destination_start: u64,
+    range_length: u64,
+}
+
+impl Correspondence {
+    pub fn new(source_start: u64, destination_start: u64, range_length: u64) -> Self {
+        Self {
+            source_start,
+            destination_start,
+            range_length,
+        }
+    }
+
+    pub fn from_line(line: &str) -> Result<Self, ParseError> {
+        let [destination, source, length] = parse_n::<3, u64>(line)?;
+        if source.checked_add(length).is_none() || destination.checked_add(length).is_none() {
+            let length_text = line.split_whitespace().last().unwrap_or_default();
+            return Err(ParseError::in_line(
+                "range runs past the largest id",
+                line,
+                length_text,
+            ));
+        }
+        Ok(Self::new(source, destination, length))
+    }
+
+    /// The ids this maps from, cut short at the largest id
+    pub fn source(&self) -> Interval<u64> {
+        Interval::new(
+            self.source_start,
+            self.source_start.saturating_add(self.range_length),
+        )
+    }
+
+    pub fn lookup(&self, idx: u64) -> Option<u64> {
+        self.source()
+            .contains(idx)
+            .then(|| self.destination_start + (idx - self.source_start))
+    }
+}
+
+#[derive(Debug)]
+pub struct ResourceMap {
+    from: String,
+    to: String,
+    map: RangeMap<u64>,
+}
+
+impl ResourceMap {
+    pub fn new(from: String, to: String) -> Self {
+        Self {
+            from,
+            to,
+            map: RangeMap::new(),
+        }
+    }
+
+    pub fn from_line(line: &str) -> Result<Self, ParseError> {
+        let name = line.split(' ').next().unwrap_or_default();
+        match name.split_once("-to-") {
+            Some((from, to)) => Ok(Self::new(from.to_string(), to.to_string())),
+            None => Err(ParseError::in_line(
+                "expected \"<from>-to-<to> map:\"",
+                line,
+                name,
+            )),
+        }
+    }
+
+    pub fn add_correspondence(&mut self, correspondence: Correspondence) {
+        self.map
+            .insert(correspondence.source(), correspondence.destination_start);
+    }
+
+    pub fn add_correspondence_from_line(&mut self, line: &str) -> Result<(), ParseError> {
+        self.add_correspondence(Correspondence::from_line(line)?);
+        Ok(())
+    }
+
+    pub fn lookup(&self, idx: u64) -> u64 {
+        self.map.get(idx)
+    }
+
+    /// Where every id in `ids` ends up
+    pub fn lookup_set(&self, ids: &IntervalSet<u64>) -> IntervalSet<u64> {
+        if log::enabled(Level::Trace) {
+            // Replays the splits `map_set` makes, one piece of the map at a time
+            let mut leftovers = ids.clone();
+            for (source, destination) in self.map.pieces() {
+                let piece = IntervalSet::from(source);
+                trace!(
+                    "{:?} into ({}-{} -> {}-{})",
+                    leftovers,
+                    source.start,
+                    source.end,
+                    destination,
+                    destination + source.len()
+                );
+                trace!("         hits: {:?}", leftovers.intersection(&piece));
+                leftovers = leftovers.difference(&piece);
+                trace!("    leftovers: {:?}", leftovers);
+            }
+        }
+        self.map.map_set(ids)
+    }
+
+    pub fn describe(&self) -> String {
+        let mut out = format!("{} to {}:\n", self.from, self.to);
+        for (source, destination) in self.map.pieces() {
+            writeln!(
+                out,
+                "    {}-{} -> {}-{}",
+                source.start,
+                source.end,
+                destination,
+                destination + source.len()
+            )
+            .unwrap();
+        }
+        out
+    }
+}
+
+#[derive(Debug)]
+pub struct SeedData {
+    seeds: Vec<u64>,
+    resource_maps: Vec<ResourceMap>,
+}
+
+impl SeedData {
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        let mut seeds: Vec<u64> = vec![];
+        let mut resource_maps: Vec<ResourceMap> = vec![];
+        let mut current_resource: ResourceMap =
+            ResourceMap::new(String::from("seed"), String::from("seed"));
+        for (i, line) in input.lines().enumerate() {
+            if i == 0 {
+                let Some(list) = line.strip_prefix("seeds: ") else {
+                    return Err(ParseError::new("expected \"seeds: <ids>\"", line).on_line(1));
+                };
+                seeds = parse_number_list(list).map_err(|e| e.within(line, list).on_line(1))?;
+            } else {
+                match line.chars().next() {
+                    Some('a'..='z') => {
+                        resource_maps.push(current_resource);
+                        current_resource =
+                            ResourceMap::from_line(line).map_err(|e| e.on_line(i + 1))?
+                    }
+                    Some('0'..='9') => current_resource
+                        .add_correspondence_from_line(line)
+                        .map_err(|e| e.on_line(i + 1))?,
+                    _ => (),
+                }
+            }
+        }
+        resource_maps.push(current_resource);
+        Ok(Self {
+            seeds,
+            resource_maps,
+        })
+    }
+
+    pub fn smallest_location_for_seed(&self, idx: u64) -> u64 {
+        let mut current_idx = idx;
+        for resource_map in &self.resource_maps {
+            let next_idx = resource_map.lookup(current_idx);
+            current_idx = next_idx;
+        }
+        current_idx
+    }
+
+    /// Where every seed in `seeds` ends up, following each map in turn
+    pub fn locations(&self, seeds: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut ids = seeds.clone();
+        for resource_map in &self.resource_maps {
+            trace!("{}", resource_map.describe().trim_end());
+            ids = resource_map.lookup_set(&ids);
+            debug!(
+                "{} ranges after {}-to-{}: {:?}",
+                ids.intervals().len(),
+                resource_map.from,
+                resource_map.to,
+                ids
+            );
+        }
+        ids
+    }
+
+    /// Smallest location for seeds `start` up to but not including `end`
+    pub fn smallest_location_for_range(&self, start: u64, end: u64) -> Option<u64> {
+        self.locations(&Interval::new(start, end).into()).min()
+    }
+
+    pub fn smallest_location_for_all_seeds(&self) -> Result<u64> {
+        self.seeds
+            .iter()
+            .map(|x| self.smallest_location_for_seed(*x))
+            .min()
+            .ok_or_else(|| Error::unsolvable("no seeds listed"))
+    }
+
+    pub fn smallest_location_for_all_ranges(&self) -> Result<u64> {
+        if self.seeds.is_empty() || !self.seeds.len().is_multiple_of(2) {
+            return Err(Error::unsolvable(
+                "seeds must come in (start, length) pairs",
+            ));
+        }
+        let seeds = self
+            .seeds
+            .chunks(2)
+            .map(|pair| match pair[0].checked_add(pair[1]) {
+                Some(end) => Ok(Interval::new(pair[0], end)),
+                None => Err(Error::unsolvable(format!(
+                    "seed range {} {} runs past the largest id",
+                    pair[0], pair[1]
+                ))),
+            })
+            .collect::<Result<_>>()?;
+        self.locations(&seeds)
+            .min()
+            .ok_or_else(|| Error::unsolvable("every seed range is empty"))
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = SeedData;
+
+    fn parse(input: &str) -> Result<SeedData> {
+        Ok(SeedData::from_string(input)?)
+    }
+
+    fn part1(seed_data: &SeedData) -> Result<Answer> {
+        Ok(seed_data.smallest_location_for_all_seeds()?.into())
+    }
+
+    fn part2(seed_data: &SeedData) -> Result<Answer> {
+        Ok(seed_data.smallest_location_for_all_ranges()?.into())
+    }
+
+    fn describe(seed_data: &SeedData) -> Option<String> {
+        Some(
+            seed_data
+                .resource_maps
+                .iter()
+                .map(|resource_map| resource_map.describe() + "\n")
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_past_the_largest_id_are_errors() {
+        let e = SeedData::from_string("seeds: 1 2\n\nseed-to-soil map:\n0 18446744073709551615 2")
+            .unwrap_err();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 24, "2"));
+
+        let seeds = SeedData::from_string("seeds: 18446744073709551615 2").unwrap();
+        assert!(matches!(
+            seeds.smallest_location_for_all_ranges(),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+}
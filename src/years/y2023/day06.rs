@@ -0,0 +1,61 @@
+use crate::error::{ParseError, Result};
+use crate::parse_number_list;
+use crate::solution::{Answer, Solution};
+use std::iter::zip;
+
+pub struct Day06;
+
+/// Times and record distances, as written after the `Time:` and `Distance:` labels
+pub struct Races {
+    times: String,
+    distances: String,
+}
+
+/// Multiply together the number of ways to beat the record in each race
+fn ways_to_win(times: &str, distances: &str) -> Result<u64, ParseError> {
+    Ok(zip(
+        parse_number_list::<f64>(times)?,
+        parse_number_list::<f64>(distances)?,
+    )
+    .map(|(l, r)| l - f64::floor((l - f64::sqrt((l * l) - (4.0 * r))) / 2.0) * 2.0 - 1.0)
+    .product::<f64>() as u64)
+}
+
+fn numbers_after(line: Option<&str>, label: &str) -> Result<String, ParseError> {
+    let Some(numbers) = line.and_then(|l| l.strip_prefix(label)) else {
+        return Err(ParseError::new(
+            format!("expected a line starting with {:?}", label),
+            line.unwrap_or_default(),
+        ));
+    };
+    parse_number_list::<u64>(numbers).map_err(|e| e.within(line.unwrap(), numbers))?;
+    Ok(numbers.to_string())
+}
+
+/// Part 2 reads each line as one number with bad kerning
+fn unkern(s: &str) -> String {
+    s.split_whitespace().collect()
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = Races;
+
+    fn parse(input: &str) -> Result<Races> {
+        let mut lines = input.lines();
+        Ok(Races {
+            times: numbers_after(lines.next(), "Time:").map_err(|e| e.on_line(1))?,
+            distances: numbers_after(lines.next(), "Distance:").map_err(|e| e.on_line(2))?,
+        })
+    }
+
+    fn part1(races: &Races) -> Result<Answer> {
+        Ok(ways_to_win(&races.times, &races.distances)?.into())
+    }
+
+    fn part2(races: &Races) -> Result<Answer> {
+        Ok(ways_to_win(&unkern(&races.times), &unkern(&races.distances))?.into())
+    }
+}
@@ -1,15 +1,8 @@
-use clap;
+use crate::error::{ParseError, Result};
+use crate::solution::{Answer, Solution};
 use std::collections::HashMap;
-use std::{fs, str};
 
-#[derive(clap::Args, Debug)]
-pub struct Args {
-    #[arg(default_value_t = String::from("./inputs/day7/input.txt"))]
-    file: String,
-
-    #[clap(long, short, action)]
-    part2: bool,
-}
+pub struct Day07;
 
 #[derive(Debug, PartialEq, Hash, Eq, Clone, Copy)]
 pub enum Card {
@@ -61,10 +54,10 @@ fn score_cardmap_of_a_kind(cardmap: &HashMap<Card, u8>) -> u64 {
             _ => score += *card as u64,
         }
     }
-    return score;
+    score
 }
 
-pub fn score_hand(hand: &Vec<Card>) -> u64 {
+pub fn score_hand(hand: &[Card]) -> u64 {
     let mut cardmap: HashMap<Card, u8> = HashMap::new();
     let mut score: u64 = 0;
     let mut factor: u64 = 1;
@@ -75,10 +68,10 @@ pub fn score_hand(hand: &Vec<Card>) -> u64 {
         factor *= 15;
     }
     score += score_cardmap_of_a_kind(&cardmap);
-    return score;
+    score
 }
 
-pub fn score_jokers(hand: &Vec<Card>) -> u64 {
+pub fn score_jokers(hand: &[Card]) -> u64 {
     let mut cardmap: HashMap<Card, u8> = HashMap::new();
     let mut score: u64 = 0;
     let mut factor: u64 = 1;
@@ -107,11 +100,10 @@ pub fn score_jokers(hand: &Vec<Card>) -> u64 {
     let count = cardmap.entry(joker_card).or_insert(0);
     *count += joker_count;
     score += score_cardmap_of_a_kind(&cardmap);
-    return score;
+    score
 }
 
-#[allow(dead_code)]
-#[derive(Eq, Debug)]
+#[derive(Eq, Debug, Clone)]
 pub struct Hand {
     cards: Vec<Card>,
     bet: u64,
@@ -129,17 +121,25 @@ impl Hand {
         Self { cards, bet, score }
     }
 
-    pub fn from_string(input: String, jokers: bool) -> Option<Self> {
+    pub fn from_string(input: &str, jokers: bool) -> Result<Self, ParseError> {
         let parts: Vec<&str> = input.split_whitespace().collect();
+        let [hand, bet] = parts[..] else {
+            return Err(ParseError::new("expected \"<cards> <bet>\"", input));
+        };
         let mut cards: Vec<Card> = vec![];
-        for c in parts[0].chars() {
-            let card = Card::from_char(c)?;
+        for (i, c) in hand.char_indices() {
+            let card = Card::from_char(c).ok_or_else(|| {
+                ParseError::in_line("unknown card", input, &hand[i..i + c.len_utf8()])
+            })?;
             cards.push(card);
         }
+        let bet: u64 = bet
+            .parse()
+            .map_err(|_| ParseError::in_line("invalid bet", input, bet))?;
         if jokers {
-            Some(Self::jokers(cards, parts[1].parse().unwrap()))
+            Ok(Self::jokers(cards, bet))
         } else {
-            Some(Self::jacks(cards, parts[1].parse().unwrap()))
+            Ok(Self::jacks(cards, bet))
         }
     }
 }
@@ -152,7 +152,7 @@ impl Ord for Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.score.partial_cmp(&other.score)
+        Some(self.cmp(other))
     }
 }
 
@@ -162,40 +162,41 @@ impl PartialEq for Hand {
     }
 }
 
-pub fn load_hands(filename: &String, jokers: bool) -> Vec<Hand> {
-    let contents = fs::read_to_string(filename).expect("I/O error");
-    return contents
-        .lines()
-        .map(|line| Hand::from_string(line.to_string(), jokers))
-        .flatten()
-        .collect();
-}
-
-pub fn part1(args: &Args) -> u64 {
-    let mut hands: Vec<Hand> = load_hands(&args.file, false);
-
+/// Total winnings: each hand's bet multiplied by its rank
+fn winnings(mut hands: Vec<Hand>) -> u64 {
     hands.sort_unstable();
-    return hands
+    hands
         .iter()
         .enumerate()
         .map(|(i, hand)| (i as u64 + 1) * hand.bet)
-        .sum();
+        .sum()
 }
 
-pub fn part2(args: &Args) -> u64 {
-    let mut hands: Vec<Hand> = load_hands(&args.file, true);
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
 
-    hands.sort_unstable();
-    return hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i as u64 + 1) * hand.bet)
-        .sum();
-}
-pub fn entrypoint(args: &Args) {
-    if !args.part2 {
-        println!("{}", part1(args));
-    } else {
-        println!("{}", part2(args));
+    type Input = Vec<Hand>;
+
+    fn parse(contents: &str) -> Result<Vec<Hand>> {
+        let mut hands = vec![];
+        for (i, line) in contents.lines().enumerate() {
+            hands.push(Hand::from_string(line, false).map_err(|e| e.on_line(i + 1))?);
+        }
+        Ok(hands)
+    }
+
+    fn part1(hands: &Vec<Hand>) -> Result<Answer> {
+        Ok(winnings(hands.clone()).into())
+    }
+
+    fn part2(hands: &Vec<Hand>) -> Result<Answer> {
+        Ok(winnings(
+            hands
+                .iter()
+                .map(|hand| Hand::jokers(hand.cards.clone(), hand.bet))
+                .collect(),
+        )
+        .into())
     }
 }
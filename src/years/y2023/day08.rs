@@ -0,0 +1,281 @@
+use crate::error::{Error, ParseError, Result};
+use crate::numtheory::crt;
+use crate::solution::{Answer, Solution};
+use std::{collections::HashMap, str::FromStr};
+
+pub struct Day08;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+/// Every step at which a ghost is on a node ending in Z
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before its path starts repeating; each happens only once
+    pub once: Vec<u64>,
+    /// Steps the first time round the repeating part, each recurring every `period` steps
+    pub repeating: Vec<u64>,
+    pub period: u64,
+}
+
+impl Cycle {
+    pub fn on_z(&self, step: u64) -> bool {
+        self.once.contains(&step)
+            || self
+                .repeating
+                .iter()
+                .any(|&first| step >= first && (step - first).is_multiple_of(self.period))
+    }
+}
+
+pub struct Program {
+    graph: HashMap<String, (String, String)>,
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// Where following instruction `at` from `node` leads
+    fn next<'a>(&'a self, node: &str, at: usize) -> Result<&'a str> {
+        let (left, right) = self
+            .graph
+            .get(node)
+            .ok_or_else(|| Error::unsolvable(format!("no node named {}", node)))?;
+        Ok(match self.instructions[at] {
+            Instruction::Left => left,
+            Instruction::Right => right,
+        })
+    }
+
+    /// Follow the instructions from `start` until the (node, instruction) state repeats,
+    /// noting every step that lands on a node ending in Z
+    pub fn cycle(&self, start: &str) -> Result<Cycle> {
+        if self.instructions.is_empty() {
+            return Err(Error::unsolvable("no instructions"));
+        }
+        let mut seen: HashMap<(&str, usize), u64> = HashMap::new();
+        let mut hits = vec![];
+        let mut node = start;
+        let mut step: u64 = 0;
+        loop {
+            let at = (step % self.instructions.len() as u64) as usize;
+            if let Some(&cycle_start) = seen.get(&(node, at)) {
+                if hits.is_empty() {
+                    return Err(Error::unsolvable(format!(
+                        "{} loops forever without reaching a node ending in Z",
+                        start
+                    )));
+                }
+                let (once, repeating) = hits.iter().partition(|&&hit| hit < cycle_start);
+                return Ok(Cycle {
+                    once,
+                    repeating,
+                    period: step - cycle_start,
+                });
+            }
+            seen.insert((node, at), step);
+            if node.ends_with('Z') {
+                hits.push(step);
+            }
+            node = self.next(node, at)?;
+            step += 1;
+        }
+    }
+
+    /// Steps from `start` to the first node ending in Z
+    pub fn run(&self, start: &str) -> Result<u64> {
+        let cycle = self.cycle(start)?;
+        Ok(*cycle.once.first().or(cycle.repeating.first()).unwrap())
+    }
+}
+
+/// Parses `AAA = (BBB, CCC)`
+fn parse_node(line: &str) -> Result<(String, (String, String)), ParseError> {
+    let Some((id, rest)) = line.split_once(" = ") else {
+        return Err(ParseError::new(
+            "expected \"<node> = (<left>, <right>)\"",
+            line,
+        ));
+    };
+    let Some((left, right)) = rest
+        .strip_prefix('(')
+        .and_then(|r| r.strip_suffix(')'))
+        .and_then(|r| r.split_once(", "))
+    else {
+        return Err(ParseError::in_line(
+            "expected \"(<left>, <right>)\"",
+            line,
+            rest,
+        ));
+    };
+    Ok((id.to_string(), (left.to_string(), right.to_string())))
+}
+
+impl FromStr for Program {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((instructions, nodes)) = s.split_once("\n\n") else {
+            return Err(ParseError::new(
+                "expected instructions and nodes separated by a blank line",
+                s.lines().next().unwrap_or_default(),
+            ));
+        };
+        // Parse the instructions
+        let mut moves: Vec<Instruction> = vec![];
+        for (i, c) in instructions.trim_end().char_indices() {
+            match c {
+                'L' => moves.push(Instruction::Left),
+                'R' => moves.push(Instruction::Right),
+                _ => {
+                    return Err(ParseError::in_line(
+                        "unknown instruction",
+                        instructions,
+                        &instructions[i..i + c.len_utf8()],
+                    )
+                    .on_line(1))
+                }
+            }
+        }
+        // Parse the tree;
+        let first_node_line = instructions.lines().count() + 2;
+        let mut graph: HashMap<String, (String, String)> = HashMap::new();
+        for (i, line) in nodes.lines().enumerate() {
+            let (id, next) = parse_node(line).map_err(|e| e.on_line(first_node_line + i))?;
+            graph.insert(id, next);
+        }
+        Ok(Program {
+            graph,
+            instructions: moves,
+        })
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Program> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(program: &Program) -> Result<Answer> {
+        Ok(program.run("AAA")?.into())
+    }
+
+    /// The ghosts can meet at one of the steps some ghost passes a Z node only once, or
+    /// else where every ghost is going round its loop, at a step that solves one congruence
+    /// per ghost
+    fn part2(program: &Program) -> Result<Answer> {
+        let cycles = program
+            .graph
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .map(|start| program.cycle(start))
+            .collect::<Result<Vec<_>>>()?;
+        if cycles.is_empty() {
+            return Err(Error::unsolvable("no nodes ending in A"));
+        }
+        let once = cycles
+            .iter()
+            .flat_map(|cycle| &cycle.once)
+            .copied()
+            .filter(|&step| cycles.iter().all(|cycle| cycle.on_z(step)))
+            .min();
+
+        // Each way of picking one looping arrival per ghost gives its own set of solutions,
+        // as long as it comes no earlier than the latest arrival picked
+        let mut solutions = vec![((0, 1), 0)];
+        for cycle in &cycles {
+            solutions = solutions
+                .iter()
+                .flat_map(|&(solution, latest)| {
+                    cycle.repeating.iter().filter_map(move |&first| {
+                        let arrival = (first as i128, cycle.period as i128);
+                        Some((crt([solution, arrival])?, latest.max(first as i128)))
+                    })
+                })
+                .collect();
+        }
+        let looping = solutions
+            .into_iter()
+            .map(|((steps, lcm), latest)| {
+                if steps < latest {
+                    steps + (latest - steps + lcm - 1) / lcm * lcm
+                } else {
+                    steps
+                }
+            })
+            .min()
+            .map(|steps| u64::try_from(steps).map_err(|_| Error::unsolvable("too many steps")))
+            .transpose()?;
+
+        let steps = once
+            .into_iter()
+            .chain(looping)
+            .min()
+            .ok_or_else(|| Error::unsolvable("the ghosts never line up"))?;
+        Ok(steps.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part2(map: &str) -> Result<Answer> {
+        Day08::part2(&Day08::parse(map)?)
+    }
+
+    #[test]
+    fn ghosts_meet_when_cycles_are_offset() {
+        // 11A reaches a Z after 2 steps and then every 2, 22A after 1 and then every 3
+        let map = "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (11Z, 11Z)\n22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)";
+        assert_eq!(part2(map).unwrap().to_string(), "4");
+    }
+
+    #[test]
+    fn ghosts_meet_after_passing_a_z_off_their_loop() {
+        // 11A passes 11Z once, then loops through 12Z at steps 3, 5, ...; 22A is on 22Z at 3, 6, ...
+        let map = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (12Z, 12Z)\n12Z = (11B, 11B)\n22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)";
+        assert_eq!(part2(map).unwrap().to_string(), "3");
+        let cycle = Day08::parse(map).unwrap().cycle("11A").unwrap();
+        assert_eq!(
+            (cycle.once, cycle.repeating, cycle.period),
+            (vec![1], vec![3], 2)
+        );
+
+        // The same, but with 11A's loop never reaching a Z
+        let map = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11Y, 11Y)\n11Y = (11B, 11B)\n22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)";
+        match part2(map) {
+            Err(Error::Unsolvable(reason)) => assert_eq!(reason, "the ghosts never line up"),
+            other => panic!("expected no solution, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn ghosts_meet_on_a_z_they_pass_only_once() {
+        let map = "L\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n22A = (22Z, 22Z)\n22Z = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)";
+        assert_eq!(part2(map).unwrap().to_string(), "1");
+    }
+
+    #[test]
+    fn ghosts_that_leave_z_for_good_never_meet() {
+        // 11A is on 11Z after 1 and 2 steps, but then leaves it for good
+        let map = "LR\n\n11A = (11Z, 11Z)\n11Z = (11B, 11Z)\n11B = (11B, 11B)\n22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22Z, 22Z)";
+        assert!(matches!(part2(map), Err(Error::Unsolvable(_))));
+    }
+
+    #[test]
+    fn unsolvable_input_is_an_error() {
+        let looping = "RL\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)";
+        assert!(matches!(
+            Day08::part1(&Day08::parse(looping).unwrap()),
+            Err(Error::Unsolvable(_))
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::{Error, Result};
+use crate::parse_number_list;
+use crate::solution::{Answer, Solution};
+
+pub struct Day09;
+
+pub fn derivative(v: &[i64]) -> Vec<i64> {
+    v.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+pub fn autoderive(v: &[i64]) -> Vec<Vec<i64>> {
+    let mut result = vec![v.to_vec()];
+    let mut next = v.to_vec();
+    while !next.iter().all(|x| *x == 0) {
+        next = derivative(&next);
+        result.push(next.clone());
+    }
+    result
+}
+
+pub fn extrapolate(v: &mut Vec<Vec<i64>>) -> &mut Vec<Vec<i64>> {
+    {
+        let len = v.len();
+        let base: &mut Vec<i64> = &mut v[len - 1];
+        base.push(0);
+    }
+    for i in 1..v.len() {
+        let level_idx = v.len() - i - 1;
+        {
+            let last_lower_level = v[level_idx + 1][v[level_idx + 1].len() - 1];
+            let level: &mut Vec<i64> = &mut v[level_idx];
+            level.push(level[level.len() - 1] + last_lower_level);
+        }
+    }
+    v
+}
+
+pub fn extrapolate_left(v: &mut Vec<Vec<i64>>) -> &mut Vec<Vec<i64>> {
+    {
+        let len = v.len();
+        let base: &mut Vec<i64> = &mut v[len - 1];
+        base.push(0);
+    }
+    for i in 1..v.len() {
+        let level_idx = v.len() - i - 1;
+        {
+            let first_lower_level = v[level_idx + 1][0];
+            let level: &mut Vec<i64> = &mut v[level_idx];
+            level.insert(0, level[0] - first_lower_level);
+        }
+    }
+    v
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>> {
+        let sequences = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_number_list(line).map_err(|e| Error::from(e.on_line(i + 1))));
+        sequences.collect()
+    }
+
+    fn part1(sequences: &Vec<Vec<i64>>) -> Result<Answer> {
+        let mut result = 0;
+        for sequence in sequences.iter() {
+            let mut layers = autoderive(sequence);
+            extrapolate(&mut layers);
+            result += layers[0][layers[0].len() - 1];
+        }
+        Ok(result.into())
+    }
+
+    fn part2(sequences: &Vec<Vec<i64>>) -> Result<Answer> {
+        let mut result = 0;
+        for sequence in sequences.iter() {
+            let mut layers = autoderive(sequence);
+            extrapolate_left(&mut layers);
+            result += layers[0][0];
+        }
+        Ok(result.into())
+    }
+}
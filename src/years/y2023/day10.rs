@@ -0,0 +1,140 @@
+use crate::debug;
+use crate::error::{Error, Result};
+use crate::graph::bfs;
+use crate::grid::{Direction, Grid, Point};
+use crate::log::{self, paint, Level};
+use crate::solution::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day10;
+
+/// Directions the pipe on a tile leads in
+fn openings(c: char) -> &'static [Direction] {
+    use Direction::*;
+    match c {
+        '|' => &[North, South],
+        '-' => &[East, West],
+        'L' => &[North, East],
+        'J' => &[North, West],
+        '7' => &[South, West],
+        'F' => &[South, East],
+        'S' => &Direction::CARDINAL,
+        _ => &[],
+    }
+}
+
+/// Tiles the pipe at `p` leads to whose own pipe leads back
+fn neighbors(world: &Grid<char>, p: Point) -> impl Iterator<Item = Point> + '_ {
+    openings(world[p]).iter().filter_map(move |&d| {
+        let q = world.step(p, d)?;
+        openings(world[q]).contains(&d.opposite()).then_some(q)
+    })
+}
+
+fn no_start() -> Error {
+    Error::unsolvable("no starting position 'S' in the map")
+}
+
+fn start(world: &Grid<char>) -> Result<Point> {
+    world.find_all(|&c| c == 'S').next().ok_or_else(no_start)
+}
+
+fn part1(world: &Grid<char>) -> Result<u64> {
+    let pipe = bfs(start(world)?, |&p| neighbors(world, p));
+    Ok(pipe.iter().map(|(_, d)| d as u64).max().unwrap_or(0))
+}
+
+/// How many loop tiles a ray from `p` towards `direction` crosses, not counting the
+/// pipe shapes in `along`, which run with the ray rather than across it
+fn crossings(
+    world: &Grid<char>,
+    walls: &HashSet<Point>,
+    p: Point,
+    direction: Direction,
+    along: &str,
+) -> usize {
+    let mut count = 0;
+    let mut t = p;
+    while let Some(next) = world.step(t, direction) {
+        if walls.contains(&next) && !along.contains(world[next]) {
+            count += 1;
+        }
+        t = next;
+    }
+    count
+}
+
+/// Whether a tile off the loop is enclosed by it
+fn is_inside(world: &Grid<char>, walls: &HashSet<Point>, p: Point) -> bool {
+    let rays = [
+        (Direction::West, "-F7"),
+        (Direction::East, "-LJ"),
+        (Direction::South, "|J7"),
+        (Direction::North, "|LF"),
+    ];
+    let mut odd = 0;
+    for (direction, along) in rays {
+        // A ray that never crosses the loop settles it, so don't cast the rest
+        match crossings(world, walls, p, direction, along) {
+            0 => return false,
+            n => odd += n % 2,
+        }
+    }
+    odd >= 3
+}
+
+fn count_inside(world: &Grid<char>, walls: &HashSet<Point>) -> u64 {
+    let inside = |p: Point| !walls.contains(&p) && is_inside(world, walls, p);
+    if log::enabled(Level::Debug) {
+        let mut map = String::new();
+        for (p, &c) in world.iter() {
+            if walls.contains(&p) {
+                map.push_str(&paint("1;32", c));
+            } else if inside(p) {
+                map.push_str(&paint("1;31", '#'));
+            } else {
+                map.push(' ');
+            }
+            if p.x + 1 == world.width() {
+                map.push('\n');
+            }
+        }
+        debug!(
+            "loop tiles (green) and inside tiles (red):\n{}",
+            map.trim_end()
+        );
+    }
+    world.points().filter(|&p| inside(p)).count() as u64
+}
+
+fn part2(world: &Grid<char>) -> Result<u64> {
+    let shift = |p: Point| Point::new(p.x + 1, p.y + 1);
+    let mut expanded_world = Grid::new(world.width() + 2, world.height() + 2, ' ');
+    for (p, &c) in world.iter() {
+        expanded_world[shift(p)] = c;
+    }
+    let pipe_nodes: HashSet<Point> = bfs(start(world)?, |&p| neighbors(world, p))
+        .iter()
+        .map(|(&p, _)| shift(p))
+        .collect();
+    Ok(count_inside(&expanded_world, &pipe_nodes))
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(world: &Grid<char>) -> Result<Answer> {
+        Ok(part1(world)?.into())
+    }
+
+    fn part2(world: &Grid<char>) -> Result<Answer> {
+        Ok(part2(world)?.into())
+    }
+}
@@ -0,0 +1,55 @@
+use crate::solution::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+
+/// Every implemented day of 2023, in order
+pub static DAYS: &[&dyn Day] = &[
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    fn parse_error(day: u8, input: &str) -> (usize, usize, String) {
+        match crate::years::get(2023, day).unwrap().parse(input) {
+            Err(Error::Parse(e)) => (e.line, e.column, e.text),
+            Err(e) => panic!("expected a parse error, got {}", e),
+            Ok(_) => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn malformed_input_reports_position() {
+        assert_eq!(
+            parse_error(2, "Game 1: 3 blue\nGame 2: 3 blu, 4 red"),
+            (2, 11, String::from("blu"))
+        );
+        assert_eq!(
+            parse_error(7, "32T3K 765\nT5XJ5 684"),
+            (2, 3, String::from("X"))
+        );
+        assert_eq!(
+            parse_error(8, "RL\n\nAAA = (BBB, CCC"),
+            (3, 7, String::from("(BBB, CCC"))
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use crate::error::{Error, Result};
+use crate::solution::{Answer, Solution};
+
+pub struct Day{{PADDED}};
+
+impl Solution for Day{{PADDED}} {
+    const DAY: u8 = {{DAY}};
+    const TITLE: &'static str = "{{TITLE}}";
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(_lines: &Vec<String>) -> Result<Answer> {
+        Err(Error::Unimplemented(Self::DAY))
+    }
+
+    fn part2(_lines: &Vec<String>) -> Result<Answer> {
+        Err(Error::Unimplemented(Self::DAY))
+    }
+}
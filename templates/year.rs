@@ -0,0 +1,4 @@
+use crate::solution::Day;
+
+/// Every implemented day of {{YEAR}}, in order
+pub static DAYS: &[&dyn Day] = &[];